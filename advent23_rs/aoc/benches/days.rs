@@ -0,0 +1,101 @@
+// times parsing and each part of every day, on dayN/example.txt and dayN/input.txt
+//
+// cargo bench -p aoc                                   run everything
+// cargo bench -p aoc -- day14/                         run one day
+// cargo bench -p aoc -- --save-baseline before         record a baseline
+// cargo bench -p aoc -- --baseline before              compare against it
+//
+// criterion keeps the reports in target/criterion, so two commits can be compared by saving
+// a baseline on one and checking out the other
+
+use common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::path::Path;
+
+fn read_input(day: u8, name: &str) -> Option<String> {
+    let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    std::fs::read_to_string(workspace_dir.join(format!("day{day}")).join(name)).ok()
+}
+
+// real_input_only lists the parts that rely on the structure of the real input
+// and don't work on the example
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, real_input_only: &[u8]) {
+    let mut group = c.benchmark_group(format!("day{day}"));
+    // a few of the parts take seconds per run
+    group.sample_size(10);
+
+    for (input_name, file_name) in [("example", "example.txt"), ("input", "input.txt")] {
+        let Some(input) = read_input(day, file_name) else {
+            eprintln!("skipping day {day} {input_name}, day{day}/{file_name} is missing");
+            continue;
+        };
+        let input = input.as_str();
+
+        let parsed = match S::parse(input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("skipping day {day} {input_name}, {}", err.locate(input));
+                continue;
+            }
+        };
+        group.bench_function(format!("parse/{input_name}"), |b| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+
+        for part in 1..=common::num_parts::<S>() {
+            if input_name == "example" && real_input_only.contains(&part) {
+                continue;
+            }
+
+            // run it once first so a failing part is skipped instead of benchmarked
+            let result = match part {
+                1 => S::part1(&parsed).map(|_| ()),
+                _ => S::part2(&parsed).map(|_| ()),
+            };
+            if let Err(err) = result {
+                eprintln!("skipping day {day} part {part} {input_name}, {err:#}");
+                continue;
+            }
+
+            group.bench_function(format!("part{part}/{input_name}"), |b| match part {
+                1 => b.iter(|| S::part1(black_box(&parsed))),
+                _ => b.iter(|| S::part2(black_box(&parsed))),
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c, 1, &[]);
+    bench_day::<day2::Day2>(c, 2, &[]);
+    bench_day::<day3::Day3>(c, 3, &[]);
+    bench_day::<day4::Day4>(c, 4, &[]);
+    bench_day::<day5::Day5>(c, 5, &[]);
+    bench_day::<day6::Day6>(c, 6, &[]);
+    bench_day::<day7::Day7>(c, 7, &[]);
+    bench_day::<day8::Day8>(c, 8, &[]);
+    bench_day::<day9::Day9>(c, 9, &[]);
+    bench_day::<day10::Day10>(c, 10, &[]);
+    bench_day::<day11::Day11>(c, 11, &[]);
+    bench_day::<day12::Day12>(c, 12, &[]);
+    bench_day::<day13::Day13>(c, 13, &[]);
+    bench_day::<day14::Day14>(c, 14, &[]);
+    bench_day::<day15::Day15>(c, 15, &[]);
+    bench_day::<day16::Day16>(c, 16, &[]);
+    bench_day::<day17::Day17>(c, 17, &[]);
+    bench_day::<day18::Day18>(c, 18, &[]);
+    bench_day::<day19::Day19>(c, 19, &[]);
+    // part 2 looks for the modules feeding rx, which the example doesn't have
+    bench_day::<day20::Day20>(c, 20, &[2]);
+    // part 2 needs a square grid with an open row and column through the start
+    bench_day::<day21::Day21>(c, 21, &[2]);
+    bench_day::<day22::Day22>(c, 22, &[]);
+    bench_day::<day23::Day23>(c, 23, &[]);
+    bench_day::<day24::Day24>(c, 24, &[]);
+    bench_day::<day25::Day25>(c, 25, &[]);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
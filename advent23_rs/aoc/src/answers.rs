@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+use common::ParseError;
+use std::{collections::HashMap, path::Path};
+
+// the known answers for the real inputs, keyed by (day, part)
+#[derive(Debug)]
+pub struct Answers(HashMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Unable to read answers from {}", path.display()))?;
+
+        Answers::parse(&contents)
+            .map_err(|err| err.locate(&contents))
+            .with_context(|| format!("Unable to parse answers in {}", path.display()))
+    }
+
+    // one answer per line: "<day> <part> <answer>", lines starting with # are comments
+    pub fn parse(contents: &str) -> Result<Self, ParseError> {
+        let mut answers = HashMap::new();
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, ' ');
+            let mut next_field = || {
+                fields
+                    .next()
+                    .filter(|field| !field.is_empty())
+                    .ok_or_else(|| ParseError::new(line, "expected a line like \"17 2 1215\""))
+            };
+
+            let day = common::parse_number(next_field()?)?;
+            let part = common::parse_number(next_field()?)?;
+            let answer = next_field()?;
+
+            if answers
+                .insert((day, part), answer.trim().to_string())
+                .is_some()
+            {
+                return Err(ParseError::new(
+                    line,
+                    "this day and part already has an answer",
+                ));
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# day part answer\n2 1 2377\n\n17 2 1215\n").unwrap();
+
+        assert_eq!(answers.get(2, 1), Some("2377"));
+        assert_eq!(answers.get(17, 2), Some("1215"));
+        assert_eq!(answers.get(17, 1), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "2 1 2377\n2 one 71220";
+        let err = Answers::parse(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "one"));
+
+        assert!(Answers::parse("2 1 2377\n2 1 2377").is_err());
+        assert!(Answers::parse("2 1").is_err());
+    }
+}
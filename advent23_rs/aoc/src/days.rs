@@ -0,0 +1,240 @@
+use anyhow::Result;
+use common::{CancellationToken, Solution, Solved};
+use day23::GridExt;
+use image::Image;
+
+// the frames of a simulation, one step at a time
+pub type Frames<'a> = Box<dyn Iterator<Item = Image> + 'a>;
+
+pub struct Day {
+    pub day: u8,
+    pub num_parts: u8,
+    // parse the input and run the given part on it, until it's done or cancelled
+    pub solve: fn(u8, &str, &CancellationToken) -> Result<Solved>,
+    // parse the input and throw away the result, to check whether it could be this day's input
+    pub parse: fn(&str) -> Result<()>,
+    // parse the input and print what the parser made of it as JSON
+    pub dump_parsed: fn(&str) -> Result<String>,
+    // parse the input and print the graph it's solved on as GraphViz DOT, for days with a graph
+    pub to_dot: Option<fn(&str) -> Result<String>>,
+    // parse the input and draw the given part's solution, for days that can be drawn
+    pub render: Option<fn(u8, &str) -> Result<Image>>,
+    // parse the input and step through the given part's simulation, for days that simulate something
+    pub frames: Option<fn(u8, &str) -> Result<Frames<'_>>>,
+    // whether solving starts threads of its own, whose CPU time the calling thread's doesn't count
+    pub spawns_threads: bool,
+}
+
+const fn day<S: Solution>(day: u8) -> Day {
+    Day {
+        day,
+        num_parts: if S::HAS_PART2 { 2 } else { 1 },
+        solve: common::solve::<S>,
+        parse: parse::<S>,
+        dump_parsed: common::dump_parsed::<S>,
+        to_dot: None,
+        render: None,
+        frames: None,
+        spawns_threads: false,
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<()> {
+    S::parse(input).map_err(|err| err.locate(input))?;
+    Ok(())
+}
+
+impl Day {
+    const fn with_dump(self, dump_parsed: fn(&str) -> Result<String>) -> Day {
+        Day {
+            dump_parsed,
+            ..self
+        }
+    }
+
+    const fn with_graph(self, to_dot: fn(&str) -> Result<String>) -> Day {
+        Day {
+            to_dot: Some(to_dot),
+            ..self
+        }
+    }
+
+    const fn with_render(self, render: fn(u8, &str) -> Result<Image>) -> Day {
+        Day {
+            render: Some(render),
+            ..self
+        }
+    }
+
+    const fn with_frames(self, frames: fn(u8, &str) -> Result<Frames<'_>>) -> Day {
+        Day {
+            frames: Some(frames),
+            ..self
+        }
+    }
+
+    const fn with_threads(self) -> Day {
+        Day {
+            spawns_threads: true,
+            ..self
+        }
+    }
+}
+
+fn day8_dot(input: &str) -> Result<String> {
+    let (_, graph) = day8::parse_input(input)?;
+    Ok(graph.to_dot())
+}
+
+fn day20_dot(input: &str) -> Result<String> {
+    Ok(day20::to_graph(&day20::parse_input(input)?).to_dot())
+}
+
+fn day23_dot(input: &str) -> Result<String> {
+    let graph = day23::parse_input(input)?.to_graph();
+    Ok(graph
+        .map_names(|&(row, column)| format!("({row}, {column})"))
+        .to_dot())
+}
+
+fn day25_dot(input: &str) -> Result<String> {
+    Ok(day25::parse_input(input)?.to_dot())
+}
+
+// both parts are about the same loop
+fn day10_render(_: u8, input: &str) -> Result<Image> {
+    Ok(day10::render(&day10::Day10::parse(input)?))
+}
+
+fn day14_render(part: u8, input: &str) -> Result<Image> {
+    let platform = day14::Day14::parse(input)?;
+    let platform = if part == 1 {
+        day14::tilted_north(platform)
+    } else {
+        day14::spun(platform)
+    };
+    Ok(platform.render())
+}
+
+fn day16_render(part: u8, input: &str) -> Result<Image> {
+    Ok(day16::render(&day16::parse_input(input)?, part == 2))
+}
+
+fn day17_render(part: u8, input: &str) -> Result<Image> {
+    day17::render(&day17::parse_input(input)?, part == 2)
+}
+
+// part 2 is too far to draw, this draws the 5 by 5 copies of the map it extrapolates from instead
+fn day21_render(part: u8, input: &str) -> Result<Image> {
+    let grid = day21::parse_input(input)?;
+    let num_steps = if part == 1 {
+        64
+    } else {
+        5 * grid.num_rows() / 2
+    };
+    Ok(day21::render(&grid, num_steps))
+}
+
+fn day23_render(part: u8, input: &str) -> Result<Image> {
+    let mut grid = day23::parse_input(input)?;
+    if part == 2 {
+        grid.replace_slopes_with_paths();
+    }
+    day23::render(&grid)
+}
+
+fn day14_frames(part: u8, input: &str) -> Result<Frames<'_>> {
+    let platform = day14::Day14::parse(input)?;
+    Ok(if part == 1 {
+        Box::new(day14::tilt_frames(platform))
+    } else {
+        Box::new(day14::spin_frames(platform))
+    })
+}
+
+fn day16_frames(part: u8, input: &str) -> Result<Frames<'_>> {
+    Ok(Box::new(day16::frames(
+        day16::parse_input(input)?,
+        part == 2,
+    )))
+}
+
+// part 1's 1000 presses, part 2 keeps pressing
+fn day20_frames(part: u8, input: &str) -> Result<Frames<'_>> {
+    let num_presses = (part == 1).then_some(1000);
+    Ok(Box::new(day20::frames(
+        day20::parse_input(input)?,
+        num_presses,
+    )))
+}
+
+// the same steps as day21_render
+fn day21_frames(part: u8, input: &str) -> Result<Frames<'_>> {
+    let grid = day21::parse_input(input)?;
+    let num_steps = if part == 1 {
+        64
+    } else {
+        5 * grid.num_rows() / 2
+    };
+    Ok(Box::new(day21::frames(&grid, num_steps)))
+}
+
+// both parts work on the bricks after they've fallen, so those are dumped next to the snapshot
+fn day22_dump(input: &str) -> Result<String> {
+    let bricks = day22::parse_input(input)?;
+    common::to_json(&serde_json::json!({
+        "settled": day22::drop_bricks(bricks.clone()),
+        "snapshot": bricks,
+    }))
+}
+
+// both parts start from the bricks falling
+fn day22_frames(_: u8, input: &str) -> Result<Frames<'_>> {
+    Ok(Box::new(day22::frames(day22::parse_input(input)?)))
+}
+
+// every day that has a solution in this workspace, in order
+pub static DAYS: [Day; 25] = [
+    day::<day1::Day1>(1),
+    day::<day2::Day2>(2),
+    day::<day3::Day3>(3),
+    day::<day4::Day4>(4),
+    day::<day5::Day5>(5),
+    day::<day6::Day6>(6),
+    day::<day7::Day7>(7),
+    day::<day8::Day8>(8).with_graph(day8_dot),
+    day::<day9::Day9>(9),
+    day::<day10::Day10>(10).with_render(day10_render),
+    day::<day11::Day11>(11),
+    day::<day12::Day12>(12),
+    day::<day13::Day13>(13),
+    day::<day14::Day14>(14)
+        .with_render(day14_render)
+        .with_frames(day14_frames),
+    day::<day15::Day15>(15),
+    day::<day16::Day16>(16)
+        .with_render(day16_render)
+        .with_frames(day16_frames)
+        .with_threads(),
+    day::<day17::Day17>(17).with_render(day17_render),
+    day::<day18::Day18>(18),
+    day::<day19::Day19>(19),
+    day::<day20::Day20>(20)
+        .with_graph(day20_dot)
+        .with_frames(day20_frames),
+    day::<day21::Day21>(21)
+        .with_render(day21_render)
+        .with_frames(day21_frames),
+    day::<day22::Day22>(22)
+        .with_dump(day22_dump)
+        .with_frames(day22_frames),
+    day::<day23::Day23>(23)
+        .with_graph(day23_dot)
+        .with_render(day23_render),
+    day::<day24::Day24>(24),
+    day::<day25::Day25>(25).with_graph(day25_dot),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,179 @@
+use crate::days::DAYS;
+use std::collections::BTreeSet;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+
+// stands for the blank lines some inputs split into sections with
+const BLANK_LINE: char = '\n';
+
+// the characters each day's inputs are written with, in day order
+const ALPHABETS: [&[&str]; 25] = [
+    &[LOWERCASE, DIGITS],
+    &["G", LOWERCASE, DIGITS, " :;,"],
+    &[DIGITS, ".#$%&*+-/=@"],
+    &["Card", DIGITS, " :|"],
+    &[LOWERCASE, DIGITS, " :-\n"],
+    &["TimeDstanc", DIGITS, " :"],
+    &["AKQJT", DIGITS, " "],
+    &["LR", UPPERCASE, DIGITS, " =(),\n"],
+    &[DIGITS, " -"],
+    &["|-LJ7F.S"],
+    &[".#"],
+    &[".#?", DIGITS, " ,"],
+    &[".#\n"],
+    &["O#."],
+    &[LOWERCASE, DIGITS, ",=-"],
+    &["|-/\\."],
+    &[DIGITS],
+    &["RDLU", DIGITS, "abcdef", " (#)"],
+    &[LOWERCASE, "AR", DIGITS, "{}<>:,=\n"],
+    &[LOWERCASE, " %&,->"],
+    &[".#S"],
+    &[DIGITS, ",~"],
+    &["#.><^v"],
+    &[DIGITS, " ,-@"],
+    &[LOWERCASE, " :"],
+];
+
+// the best scores closer together than this are too close to call
+const AMBIGUITY_MARGIN: f64 = 0.25;
+
+// a day whose parser accepts the input and whose alphabet has every character in it, scored from
+// 0 to 1 by how much of the alphabet the input uses. a grid of . and # fits days 11, 13, 14, 21
+// and 23, but only uses all of day 11's alphabet
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub day: u8,
+    pub score: f64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Identification {
+    Day(u8),
+    // these days all match about as well as each other
+    Ambiguous(Vec<u8>),
+    // no day's parser and alphabet both fit the input
+    Unknown,
+}
+
+fn alphabet(day: u8) -> BTreeSet<char> {
+    ALPHABETS[day as usize - 1]
+        .iter()
+        .flat_map(|chars| chars.chars())
+        .collect()
+}
+
+// the characters in the input, with BLANK_LINE if it has blank lines in it
+fn input_alphabet(input: &str) -> BTreeSet<char> {
+    let mut chars = input
+        .chars()
+        .filter(|&c| c != '\n' && c != '\r')
+        .collect::<BTreeSet<_>>();
+
+    if input.trim().lines().any(|line| line.trim().is_empty()) {
+        chars.insert(BLANK_LINE);
+    }
+
+    chars
+}
+
+// every day the input could be for, best match first. day 1's parser takes any lines at all,
+// so the alphabets rule out more days than the parsers do
+pub fn candidates(input: &str) -> Vec<Candidate> {
+    let input_alphabet = input_alphabet(input);
+    if input_alphabet.is_empty() {
+        return Vec::new();
+    }
+
+    let mut candidates = DAYS
+        .iter()
+        .filter_map(|day| {
+            let alphabet = alphabet(day.day);
+            if !input_alphabet.is_subset(&alphabet) || (day.parse)(input).is_err() {
+                return None;
+            }
+
+            Some(Candidate {
+                day: day.day,
+                score: input_alphabet.len() as f64 / alphabet.len() as f64,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.day.cmp(&b.day)));
+    candidates
+}
+
+// which day the candidates point to, candidates being best first like candidates gives them
+pub fn identify(candidates: &[Candidate]) -> Identification {
+    let Some(best) = candidates.first() else {
+        return Identification::Unknown;
+    };
+
+    let close = candidates
+        .iter()
+        .filter(|candidate| best.score - candidate.score < AMBIGUITY_MARGIN)
+        .map(|candidate| candidate.day)
+        .collect::<Vec<_>>();
+
+    match close[..] {
+        [day] => Identification::Day(day),
+        _ => Identification::Ambiguous(close),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identify_input(input: &str) -> Identification {
+        identify(&candidates(input))
+    }
+
+    #[test]
+    fn test_grids() {
+        let grids = [
+            (10, "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ..."),
+            (11, "...#..\n......\n#....."),
+            (13, "#.##.\n..#.#\n\n#...#\n##..#"),
+            (14, "O....#\n.O.#..\n..O..#"),
+            (16, ".|..\\.\n|.-./.\n......"),
+            (21, "...........\n.....###.#.\n.###.##..#.\n..#.#...#..\n....#.#....\n.##..S####.\n.##..#...#.\n.......##..\n.##.#.####.\n.##..##.##.\n..........."),
+            (23, "#.###\n#.>.#\n#v#.#\n###.#"),
+        ];
+
+        for (day, input) in grids {
+            assert_eq!(identify_input(input), Identification::Day(day), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            identify_input("Game 1: 3 blue, 4 red; 1 red, 2 green"),
+            Identification::Day(2)
+        );
+        assert_eq!(
+            identify_input("0 3 6 9 12 15\n1 3 6 10 15 21"),
+            Identification::Day(9)
+        );
+        assert_eq!(
+            identify_input("jqt: rhn xhk nvd\nrsh: frs pzl lsr"),
+            Identification::Day(25)
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_and_unknown() {
+        // nothing but empty space fits most of the grids about as well
+        assert_eq!(
+            identify_input("...\n...\n..."),
+            Identification::Ambiguous(vec![11, 13, 14])
+        );
+
+        assert_eq!(identify_input(""), Identification::Unknown);
+        assert_eq!(identify_input("Hello, World!"), Identification::Unknown);
+    }
+}
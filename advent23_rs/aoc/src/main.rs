@@ -0,0 +1,855 @@
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use common::{CancellationToken, Solved};
+use serde::Serialize;
+use std::{
+    io::{self, Read, Write},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+mod answers;
+mod days;
+mod identify;
+mod parallel;
+mod python;
+
+use answers::Answers;
+use days::{Day, DAYS};
+use identify::Identification;
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day in sequence
+    Run(RunArgs),
+    /// Check every day's answers on its input.txt against the recorded answers
+    Verify(VerifyArgs),
+    /// Print a random puzzle input for a day, to stress test its solution
+    Generate(GenerateArgs),
+    /// Check that the Python solutions in advent23_py give the same answers
+    Parity(ParityArgs),
+    /// Print the graph a day is solved on in GraphViz DOT format
+    Graph(GraphArgs),
+    /// Draw a day's solution as a PNG or PPM image
+    Render(RenderArgs),
+    /// Replay a day's simulation in the terminal, a step per frame
+    Animate(AnimateArgs),
+    /// Work out which day a puzzle input is for
+    Identify(IdentifyArgs),
+    /// List the day 2 games that couldn't have been played with a given bag of cubes, or work out
+    /// which bag they were most likely played with
+    Cubes(CubesArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Part to run, both parts are run if this is left out
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Path to the puzzle input, or - to read it from stdin. Defaults to dayN/input.txt
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+
+    /// Run every day in sequence on its own input.txt
+    #[arg(long)]
+    all: bool,
+
+    /// Print one JSON object per line with the answer and the parse and solve times
+    #[arg(long)]
+    json: bool,
+
+    /// Run the days at the same time on a pool of threads, then print how long each took,
+    /// slowest first. Needs --all
+    #[arg(long, conflicts_with_all = ["day", "json"])]
+    parallel: bool,
+
+    /// Give up on a part that takes longer than this many seconds
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Print how far the slow parts have got to stderr every second
+    #[arg(long)]
+    progress: bool,
+
+    /// Most days to run at once, implies --parallel. Defaults to the number of CPUs
+    #[arg(long, conflicts_with_all = ["day", "json"], value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: Option<u64>,
+
+    /// Print the parsed input as JSON instead of solving it, with map keys sorted so the output of
+    /// two versions of a parser can be diffed
+    #[arg(long, conflicts_with_all = ["all", "part", "json"])]
+    dump_parsed: bool,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for. Every day but 8 and 21 has a generator, those days' part 2
+    /// only solves inputs shaped like the real ones
+    #[arg(long)]
+    day: u8,
+
+    /// How big the input is, what this counts depends on the day
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+
+    /// Seed for the random generator, the same seed and size always give the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+#[derive(Args)]
+struct ParityArgs {
+    /// Only compare this day
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Path to the puzzle input to give both solutions. Defaults to dayN/input.txt
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    /// Python interpreter to run the Python solutions with
+    #[arg(long, default_value = "python3")]
+    python: String,
+}
+
+#[derive(Args)]
+struct GraphArgs {
+    /// Day to print the graph of
+    #[arg(long)]
+    day: u8,
+
+    /// Path to the puzzle input, or - to read it from stdin. Defaults to dayN/input.txt
+    #[arg(long)]
+    input: Option<String>,
+}
+
+#[derive(Args)]
+struct RenderArgs {
+    /// Day to draw
+    #[arg(long)]
+    day: u8,
+
+    /// Which part's solution to draw
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Path to the puzzle input, or - to read it from stdin. Defaults to dayN/input.txt
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Where to write the image, the format comes from the extension: .png or .ppm
+    #[arg(long)]
+    output: PathBuf,
+
+    /// Width and height in pixels of each tile
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+}
+
+#[derive(Args)]
+struct IdentifyArgs {
+    /// Path to the puzzle input, or - to read it from stdin
+    #[arg(long)]
+    input: String,
+}
+
+#[derive(Args)]
+struct CubesArgs {
+    /// What's in the bag, written like a reveal: "12 red, 13 green, 14 blue"
+    #[arg(long, value_parser = parse_bag, required_unless_present = "infer")]
+    bag: Option<day2::Bag>,
+
+    /// Work out the most likely bag for each game and for all of them together, taking each
+    /// reveal as cubes drawn from the bag without replacement
+    #[arg(long)]
+    infer: bool,
+
+    /// Most cubes an inferred bag can have, every size up to this is tried
+    #[arg(long, default_value_t = 100, requires = "infer", value_parser = clap::value_parser!(u32).range(1..=10_000))]
+    max_cubes: u32,
+
+    /// Path to the puzzle input, or - to read it from stdin. Defaults to day2/input.txt
+    #[arg(long)]
+    input: Option<String>,
+}
+
+fn parse_bag(bag: &str) -> Result<day2::Bag, String> {
+    day2::Bag::try_from(bag).map_err(|err| err.locate(bag).to_string())
+}
+
+#[derive(Args)]
+struct AnimateArgs {
+    /// Day to replay
+    #[arg(long)]
+    day: u8,
+
+    /// Which part's simulation to replay
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Path to the puzzle input. Defaults to dayN/input.txt
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Milliseconds to wait between frames
+    #[arg(long, default_value_t = 50, conflicts_with = "step")]
+    delay: u64,
+
+    /// Wait for Enter before each frame instead, q then Enter stops
+    #[arg(long)]
+    step: bool,
+
+    /// Width and height in pixels of each tile, a pixel is a character wide and half a line high
+    #[arg(long, default_value_t = 1)]
+    scale: usize,
+}
+
+// a line of `aoc run --json` output
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    parse_ms: f64,
+    solve_ms: f64,
+}
+
+impl<'a> JsonAnswer<'a> {
+    fn new(day: u8, part: u8, solved: &'a Solved) -> Self {
+        JsonAnswer {
+            day,
+            part,
+            answer: &solved.answer,
+            parse_ms: solved.parse_time.as_secs_f64() * 1000.0,
+            solve_ms: solved.solve_time.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// File with the recorded answers. Defaults to answers.txt in the workspace root
+    #[arg(long)]
+    answers: Option<PathBuf>,
+
+    /// Fail a part that takes longer than this many seconds
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+}
+
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    let seconds = seconds.parse::<f64>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|err| err.to_string())
+}
+
+// how long a part can take, and whether to say how it's getting on
+#[derive(Clone, Copy, Default)]
+struct SolveOptions {
+    timeout: Option<Duration>,
+    progress: bool,
+}
+
+impl SolveOptions {
+    // a fresh token for each part, so the timeout starts when the part does
+    fn cancellation_token(&self, day: u8, part: u8) -> CancellationToken {
+        let mut cancel = CancellationToken::new();
+
+        if let Some(timeout) = self.timeout {
+            cancel = cancel.with_timeout(timeout);
+        }
+
+        if self.progress {
+            // the token reports far more often than anyone wants to read
+            let last_report = Mutex::new(Instant::now());
+            cancel = cancel.with_progress(move |num_steps| {
+                let mut last_report = last_report.lock().unwrap();
+                if last_report.elapsed() >= Duration::from_secs(1) {
+                    eprintln!("day {day} part {part}: {num_steps} steps");
+                    *last_report = Instant::now();
+                }
+            });
+        }
+
+        cancel
+    }
+}
+
+// the workspace root, where the dayN directories with the input files live
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn default_input_path(day: u8) -> PathBuf {
+    workspace_dir().join(format!("day{day}")).join("input.txt")
+}
+
+fn read_input(day: u8, input: Option<&str>) -> Result<String> {
+    match input {
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .context("Unable to read puzzle input from stdin")?;
+            Ok(buf)
+        }
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("Unable to read puzzle input from \"{path}\"")),
+        None => {
+            let path = default_input_path(day);
+            std::fs::read_to_string(&path)
+                .with_context(|| format!("Unable to read puzzle input from {}", path.display()))
+        }
+    }
+}
+
+fn run_day(
+    day: &Day,
+    part: Option<u8>,
+    input: &str,
+    json: bool,
+    options: SolveOptions,
+) -> Result<()> {
+    let parts = match part {
+        Some(part) => part..=part,
+        None => 1..=day.num_parts,
+    };
+
+    for part in parts {
+        let cancel = options.cancellation_token(day.day, part);
+        let solved = (day.solve)(part, input, &cancel)?;
+        if json {
+            let line = serde_json::to_string(&JsonAnswer::new(day.day, part, &solved))?;
+            println!("{line}");
+        } else {
+            println!("day {} part {part}: {}", day.day, solved.answer);
+        }
+    }
+
+    Ok(())
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let options = SolveOptions {
+        timeout: args.timeout,
+        progress: args.progress,
+    };
+
+    if args.all {
+        let mut days = Vec::new();
+        for day in &DAYS {
+            // day 25 has no part 2
+            if args.part.is_some_and(|part| part > day.num_parts) {
+                continue;
+            }
+            // not every day has its input checked in, like day 1
+            if !default_input_path(day.day).is_file() {
+                eprintln!("day {}: skipped (no input.txt)", day.day);
+                continue;
+            }
+            days.push((day, read_input(day.day, None)?));
+        }
+
+        if args.parallel || args.jobs.is_some() {
+            return run_parallel(&days, args.part, args.jobs, options);
+        }
+
+        for (day, input) in days {
+            run_day(day, args.part, &input, args.json, options)
+                .with_context(|| format!("Day {} failed", day.day))?;
+        }
+        return Ok(());
+    }
+
+    let Some(day) = args.day else {
+        bail!("either --day or --all is required");
+    };
+    let day = find_day(day)?;
+
+    let input = read_input(day.day, args.input.as_deref())?;
+    if args.dump_parsed {
+        println!("{}", (day.dump_parsed)(&input)?);
+        return Ok(());
+    }
+
+    run_day(day, args.part, &input, args.json, options)
+}
+
+fn run_parallel(
+    days: &[(&Day, String)],
+    part: Option<u8>,
+    jobs: Option<u64>,
+    options: SolveOptions,
+) -> Result<()> {
+    let num_threads = match jobs {
+        Some(jobs) => jobs as usize,
+        None => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+    };
+
+    let start = Instant::now();
+    let runs = parallel::run_days(days, part, num_threads, options);
+    let wall_time = start.elapsed();
+
+    // the answers come out in day order, like without --parallel
+    for run in &runs {
+        for (part, result) in &run.parts {
+            match result {
+                Ok(solved) => println!("day {} part {part}: {}", run.day, solved.answer),
+                Err(err) => println!("day {} part {part}: FAIL ({err})", run.day),
+            }
+        }
+    }
+
+    let mut slowest = runs.iter().collect::<Vec<_>>();
+    slowest.sort_by_key(|run| std::cmp::Reverse(run.wall_time));
+
+    println!();
+    println!("day        wall         cpu  status");
+    for run in &slowest {
+        let cpu_time = match run.cpu_time {
+            Some(cpu_time) if run.cpu_time_partial => format!("{cpu_time:.2?}+"),
+            Some(cpu_time) => format!("{cpu_time:.2?}"),
+            None => "-".to_string(),
+        };
+        let status = match run.num_failed() {
+            0 => "ok".to_string(),
+            num_failed => format!("{num_failed} failed"),
+        };
+        println!(
+            "{:>3}  {:>10}  {cpu_time:>10}  {status}",
+            run.day,
+            format!("{:.2?}", run.wall_time)
+        );
+    }
+
+    let cpu_time = runs.iter().filter_map(|run| run.cpu_time).sum::<Duration>();
+    let partial = runs.iter().any(|run| run.cpu_time_partial);
+    println!(
+        "{} days, {num_threads} at a time, in {wall_time:.2?} with {cpu_time:.2?}{} of CPU time",
+        runs.len(),
+        if partial { "+" } else { "" }
+    );
+    if partial {
+        println!("+ leaves out the threads the day starts itself, --jobs 1 counts them too");
+    }
+
+    let num_failed = runs.iter().map(parallel::DayRun::num_failed).sum::<usize>();
+    if num_failed > 0 {
+        bail!("{num_failed} parts failed");
+    }
+
+    Ok(())
+}
+
+fn find_day(day: u8) -> Result<&'static Day> {
+    match days::find(day) {
+        Some(day) => Ok(day),
+        None => bail!(
+            "there is no solution for day {day}, days {} to {} are available",
+            DAYS[0].day,
+            DAYS[DAYS.len() - 1].day
+        ),
+    }
+}
+
+fn verify(args: VerifyArgs) -> Result<()> {
+    let answers_path = args
+        .answers
+        .unwrap_or_else(|| workspace_dir().join("answers.txt"));
+    let answers = Answers::load(&answers_path)?;
+
+    let days = match args.day {
+        Some(day) => std::slice::from_ref(find_day(day)?),
+        None => &DAYS[..],
+    };
+
+    let options = SolveOptions {
+        timeout: args.timeout,
+        ..SolveOptions::default()
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days {
+        let input_path = default_input_path(day.day);
+        let input = match std::fs::read_to_string(&input_path) {
+            Ok(input) => Some(input),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => {
+                return Err(err).with_context(|| format!("Unable to read {}", input_path.display()))
+            }
+        };
+
+        for part in 1..=day.num_parts {
+            let label = format!("day {:>2} part {part}", day.day);
+            let expected = answers.get(day.day, part);
+
+            let Some(input) = &input else {
+                println!("{label}: missing (no input.txt)");
+                missing += 1;
+                continue;
+            };
+
+            let cancel = options.cancellation_token(day.day, part);
+            let solved = match (day.solve)(part, input, &cancel) {
+                Ok(solved) => solved,
+                Err(err) => {
+                    println!("{label}: FAIL ({err:#})");
+                    failed += 1;
+                    continue;
+                }
+            };
+            let timings = format!(
+                "parse {:.2?}, solve {:.2?}",
+                solved.parse_time, solved.solve_time
+            );
+
+            match expected {
+                // answers that span several lines (day 24 part 2) are too long to echo
+                None if solved.answer.contains('\n') => {
+                    println!("{label}: missing (no recorded answer) [{timings}]");
+                    missing += 1;
+                }
+                None => {
+                    println!(
+                        "{label}: missing (no recorded answer, got {}) [{timings}]",
+                        solved.answer
+                    );
+                    missing += 1;
+                }
+                Some(expected) if expected == solved.answer => {
+                    println!("{label}: pass [{timings}]");
+                    passed += 1;
+                }
+                Some(expected) => {
+                    println!(
+                        "{label}: FAIL (expected {expected}, got {}) [{timings}]",
+                        solved.answer
+                    );
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        bail!("{failed} answers did not match");
+    }
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<()> {
+    let Some(input) = inputgen::generate(args.day, args.size, args.seed) else {
+        bail!(
+            "there is no input generator for day {}, there are generators for days {:?}",
+            args.day,
+            inputgen::DAYS
+        );
+    };
+
+    print!("{input}");
+    Ok(())
+}
+
+fn parity(args: ParityArgs) -> Result<()> {
+    let solutions_dir = python::solutions_dir(workspace_dir());
+    let days = match args.day {
+        Some(day) => day..=day,
+        None => 1..=25,
+    };
+
+    let (mut matched, mut mismatched, mut skipped) = (0, 0, 0);
+    for day in days {
+        let label = format!("day {day:>2}");
+
+        if !python::has_solver(&solutions_dir, day) {
+            println!("{label}: skipped (no Python solution)");
+            skipped += 1;
+            continue;
+        }
+        let Some(rust_day) = days::find(day) else {
+            println!("{label}: skipped (no Rust solution)");
+            skipped += 1;
+            continue;
+        };
+        if args.input.is_none() && !default_input_path(day).is_file() {
+            println!("{label}: skipped (no input.txt)");
+            skipped += 1;
+            continue;
+        }
+
+        let input = read_input(day, args.input.as_deref())?;
+        let python_answers = python::solve(&args.python, &solutions_dir, day, &input)
+            .with_context(|| format!("Day {day} failed"))?;
+        if python::is_stub(&python_answers) {
+            println!("{label}: skipped (the Python solution is a stub)");
+            skipped += 1;
+            continue;
+        }
+
+        for part in 1..=rust_day.num_parts {
+            let label = format!("{label} part {part}");
+            let rust_answer = (rust_day.solve)(part, &input, &CancellationToken::new())
+                .map(|solved| solved.answer)
+                .unwrap_or_else(|err| format!("error: {err:#}"));
+            let python_answer = python_answers
+                .get(part as usize - 1)
+                .map_or("no answer", String::as_str);
+
+            if rust_answer == python_answer {
+                println!("{label}: match ({rust_answer})");
+                matched += 1;
+            } else {
+                println!("{label}: MISMATCH (Rust {rust_answer}, Python {python_answer})");
+                mismatched += 1;
+            }
+        }
+    }
+
+    println!("{matched} matched, {mismatched} mismatched, {skipped} days skipped");
+    if mismatched > 0 {
+        bail!("{mismatched} answers differ between Rust and Python");
+    }
+
+    Ok(())
+}
+
+fn graph(args: GraphArgs) -> Result<()> {
+    let day = find_day(args.day)?;
+    let Some(to_dot) = day.to_dot else {
+        bail!(
+            "day {} isn't solved on a graph, days {:?} are",
+            day.day,
+            DAYS.iter()
+                .filter(|day| day.to_dot.is_some())
+                .map(|day| day.day)
+                .collect::<Vec<_>>()
+        );
+    };
+
+    let input = read_input(day.day, args.input.as_deref())?;
+    print!("{}", to_dot(&input)?);
+    Ok(())
+}
+
+fn render(args: RenderArgs) -> Result<()> {
+    let day = find_day(args.day)?;
+    let Some(render) = day.render else {
+        bail!(
+            "day {} can't be drawn, days {:?} can",
+            day.day,
+            DAYS.iter()
+                .filter(|day| day.render.is_some())
+                .map(|day| day.day)
+                .collect::<Vec<_>>()
+        );
+    };
+    if args.part > day.num_parts {
+        bail!("day {} has no part {}", day.day, args.part);
+    }
+
+    let input = read_input(day.day, args.input.as_deref())?;
+    let image = render(args.part, &input)?;
+    image::save(&image::scale(&image, args.scale), &args.output)
+        .with_context(|| format!("Unable to write {}", args.output.display()))
+}
+
+fn animate(args: AnimateArgs) -> Result<()> {
+    let day = find_day(args.day)?;
+    let Some(frames) = day.frames else {
+        bail!(
+            "day {} doesn't simulate anything, days {:?} do",
+            day.day,
+            DAYS.iter()
+                .filter(|day| day.frames.is_some())
+                .map(|day| day.day)
+                .collect::<Vec<_>>()
+        );
+    };
+    if args.part > day.num_parts {
+        bail!("day {} has no part {}", day.day, args.part);
+    }
+    // stdin is for stepping through the frames
+    if args.input.as_deref() == Some("-") {
+        bail!("the puzzle input can't be read from stdin while animating");
+    }
+
+    let input = read_input(day.day, args.input.as_deref())?;
+    let mut stdout = io::stdout().lock();
+    // clear the screen once, each frame is then drawn over the last one
+    write!(stdout, "\x1b[2J")?;
+
+    for (i, frame) in frames(args.part, &input)?.enumerate() {
+        let frame = image::to_ansi(&image::scale(&frame, args.scale));
+        // back to the top left, then the caption clears whatever is left of its line
+        write!(
+            stdout,
+            "\x1b[H{frame}day {} part {} frame {}\x1b[K",
+            day.day,
+            args.part,
+            i + 1
+        )?;
+
+        if args.step {
+            write!(stdout, " (Enter for the next frame, q to stop) ")?;
+            stdout.flush()?;
+
+            let mut line = String::new();
+            if io::stdin().read_line(&mut line)? == 0 || line.trim() == "q" {
+                break;
+            }
+        } else {
+            stdout.flush()?;
+            thread::sleep(Duration::from_millis(args.delay));
+        }
+    }
+
+    writeln!(stdout)?;
+    Ok(())
+}
+
+fn identify(args: IdentifyArgs) -> Result<()> {
+    // the input is read the same way whatever the day
+    let input = read_input(0, Some(&args.input))?;
+
+    let candidates = identify::candidates(&input);
+    for candidate in &candidates {
+        println!("day {:>2}: {:.2}", candidate.day, candidate.score);
+    }
+
+    match identify::identify(&candidates) {
+        Identification::Day(day) => println!("this is the input for day {day}"),
+        Identification::Ambiguous(days) => {
+            bail!("this could be the input for any of days {days:?}")
+        }
+        Identification::Unknown => bail!("this doesn't look like the input for any day"),
+    }
+
+    Ok(())
+}
+
+fn cubes(args: CubesArgs) -> Result<()> {
+    let input = read_input(2, args.input.as_deref())?;
+    let games = day2::parse_puzzle_input(&input).map_err(|err| err.locate(&input))?;
+
+    if let Some(bag) = &args.bag {
+        report_impossible_games(&games, bag);
+    }
+
+    if args.infer {
+        if args.bag.is_some() {
+            println!();
+        }
+        report_likely_bags(&games, args.bag.as_ref(), args.max_cubes);
+    }
+
+    Ok(())
+}
+
+fn report_impossible_games(games: &[day2::Game], bag: &day2::Bag) {
+    let mut num_impossible = 0;
+    for (id, impossible) in day2::impossible_games(games, bag) {
+        println!("game {id}: {impossible}");
+        num_impossible += 1;
+    }
+
+    // the ids are what get added up, so a gap doesn't change the sum, but is worth knowing about
+    let missing_ids = day2::missing_ids(games)
+        .into_iter()
+        .map(|ids| match (ids.start(), ids.end()) {
+            (start, end) if start == end => start.to_string(),
+            (start, end) => format!("{start}-{end}"),
+        })
+        .collect::<Vec<_>>();
+    if !missing_ids.is_empty() {
+        println!("there are no games with ids {}", missing_ids.join(", "));
+    }
+
+    println!(
+        "{} of {} games are possible, their ids add up to {}",
+        games.len() - num_impossible,
+        games.len(),
+        day2::sum_possible(games, bag)
+    );
+}
+
+// the most likely bag for each game, then for every game played with the same bag, compared
+// with some other bags it could have been
+fn report_likely_bags(games: &[day2::Game], bag: Option<&day2::Bag>, max_cubes: u32) {
+    if games.is_empty() {
+        println!("there are no games to infer a bag from");
+        return;
+    }
+
+    for game in games {
+        let inference = day2::infer_bag(game.reveals(), max_cubes);
+        println!(
+            "game {}: {} (log likelihood {:.2})",
+            game.id(),
+            inference.bag,
+            inference.log_likelihood
+        );
+    }
+
+    let reveals = || games.iter().flat_map(day2::Game::reveals);
+    let inference = day2::infer_bag(reveals(), max_cubes);
+    println!(
+        "all games: {} (log likelihood {:.2})",
+        inference.bag, inference.log_likelihood
+    );
+
+    let mut candidates = vec![
+        (inference.bag, "most likely"),
+        (day2::smallest_bag(reveals()), "smallest possible"),
+        (day2::Bag::part1(), "part 1"),
+    ];
+    if let Some(bag) = bag {
+        candidates.push((bag.clone(), "--bag"));
+    }
+    let mut candidates = candidates
+        .into_iter()
+        .map(|(bag, label)| (day2::log_likelihood(&bag, reveals()), bag, label))
+        .collect::<Vec<_>>();
+    candidates.sort_by(|(a, ..), (b, ..)| b.total_cmp(a));
+
+    println!();
+    println!("log likelihood of all games  bag");
+    for (log_likelihood, bag, label) in candidates {
+        println!("{log_likelihood:>28.2}  {bag} ({label})");
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Generate(args) => generate(args),
+        Command::Parity(args) => parity(args),
+        Command::Graph(args) => graph(args),
+        Command::Render(args) => render(args),
+        Command::Animate(args) => animate(args),
+        Command::Identify(args) => identify(args),
+        Command::Cubes(args) => cubes(args),
+    }
+}
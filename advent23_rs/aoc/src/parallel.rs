@@ -0,0 +1,245 @@
+use crate::{days::Day, SolveOptions};
+use common::Solved;
+use std::{
+    any::Any,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+// how a day went on the thread pool
+pub struct DayRun {
+    pub day: u8,
+    // each part with its answer, or why it has none
+    pub parts: Vec<(u8, Result<Solved, String>)>,
+    pub wall_time: Duration,
+    // None where the CPU time can't be measured
+    pub cpu_time: Option<Duration>,
+    // cpu_time leaves out the threads the day started, see run_day
+    pub cpu_time_partial: bool,
+}
+
+impl DayRun {
+    pub fn num_failed(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|(_, result)| result.is_err())
+            .count()
+    }
+}
+
+// runs each day on its input with up to num_threads days at a time, in day order. a day that
+// panics fails on its own and leaves the others running
+pub fn run_days(
+    days: &[(&Day, String)],
+    part: Option<u8>,
+    num_threads: usize,
+    options: SolveOptions,
+) -> Vec<DayRun> {
+    // the workers take the next day nobody has started on until there are none left
+    let next = AtomicUsize::new(0);
+
+    let num_workers = num_threads.clamp(1, days.len().max(1));
+
+    let mut runs = thread::scope(|s| {
+        let workers = (0..num_workers)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut runs = Vec::new();
+                    while let Some((day, input)) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let parts = match part {
+                            Some(part) => part..=part,
+                            None => 1..=day.num_parts,
+                        };
+                        runs.push(run_day(day, input, parts, options, num_workers == 1));
+                    }
+                    runs
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // panics are caught around each part, so the workers themselves can't panic
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    runs.sort_unstable_by_key(|run| run.day);
+    runs
+}
+
+// with the day running on its own, the whole process's CPU time is the day's, threads it starts
+// included. next to other days only the worker thread's own CPU time can be told apart
+fn run_day(
+    day: &Day,
+    input: &str,
+    parts: RangeInclusive<u8>,
+    options: SolveOptions,
+    alone: bool,
+) -> DayRun {
+    let cpu_time = if alone {
+        process_cpu_time
+    } else {
+        thread_cpu_time
+    };
+
+    let start = Instant::now();
+    let cpu_start = cpu_time();
+
+    let parts = parts
+        .map(|part| {
+            let cancel = options.cancellation_token(day.day, part);
+            let solve = || (day.solve)(part, input, &cancel);
+            let result = match panic::catch_unwind(AssertUnwindSafe(solve)) {
+                Ok(Ok(solved)) => Ok(solved),
+                Ok(Err(err)) => Err(format!("{err:#}")),
+                Err(payload) => Err(format!("panicked: {}", panic_message(&*payload))),
+            };
+            (part, result)
+        })
+        .collect();
+
+    DayRun {
+        day: day.day,
+        parts,
+        wall_time: start.elapsed(),
+        cpu_time: cpu_time().zip(cpu_start).map(|(end, start)| end - start),
+        cpu_time_partial: day.spawns_threads && !alone,
+    }
+}
+
+// panic! with a message gives a String, and a &str if the message has no arguments
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+#[cfg(unix)]
+fn cpu_time(clock: libc::clockid_t) -> Option<Duration> {
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: time is a valid timespec for clock_gettime to write to
+    let result = unsafe { libc::clock_gettime(clock, &mut time) };
+
+    (result == 0).then(|| Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+}
+
+// the CPU time the calling thread has used so far
+#[cfg(unix)]
+fn thread_cpu_time() -> Option<Duration> {
+    cpu_time(libc::CLOCK_THREAD_CPUTIME_ID)
+}
+
+// the CPU time every thread of the process has used so far
+#[cfg(unix)]
+fn process_cpu_time() -> Option<Duration> {
+    cpu_time(libc::CLOCK_PROCESS_CPUTIME_ID)
+}
+
+#[cfg(not(unix))]
+fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+#[cfg(not(unix))]
+fn process_cpu_time() -> Option<Duration> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use anyhow::{bail, Result};
+    use common::CancellationToken;
+
+    fn panics(_: u8, _: &str, _: &CancellationToken) -> Result<Solved> {
+        panic!("this day always panics")
+    }
+
+    fn fails(_: u8, _: &str, _: &CancellationToken) -> Result<Solved> {
+        bail!("this day always fails")
+    }
+
+    #[test]
+    fn test_run_days() {
+        let panicking = Day {
+            solve: panics,
+            ..*days::find(3).unwrap()
+        };
+        let failing = Day {
+            day: 4,
+            solve: fails,
+            ..*days::find(4).unwrap()
+        };
+        let day6 = days::find(6).unwrap();
+
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+        let days = [
+            (day6, input.to_string()),
+            (&panicking, String::new()),
+            (&failing, String::new()),
+        ];
+        let runs = run_days(&days, None, 2, SolveOptions::default());
+
+        assert_eq!(
+            runs.iter().map(|run| run.day).collect::<Vec<_>>(),
+            [3, 4, 6]
+        );
+        assert_eq!(runs[0].num_failed(), 2);
+        assert_eq!(
+            runs[0].parts[0].1.as_ref().unwrap_err(),
+            "panicked: this day always panics"
+        );
+        assert_eq!(
+            runs[1].parts[1].1.as_ref().unwrap_err(),
+            "this day always fails"
+        );
+
+        // the other days still finish
+        assert_eq!(runs[2].num_failed(), 0);
+        let answers = runs[2]
+            .parts
+            .iter()
+            .map(|(_, result)| result.as_ref().unwrap().answer.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(answers, ["288", "71503"]);
+        assert!(runs[2].cpu_time.is_some());
+
+        assert_eq!(
+            run_days(&days, Some(2), 1, SolveOptions::default())[2]
+                .parts
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_cpu_time_partial() {
+        let day16 = days::find(16).unwrap();
+        let days = [
+            (day16, ".|.\n-.\\\n/..".to_string()),
+            (days::find(6).unwrap(), "Time: 7\nDistance: 9".to_string()),
+        ];
+
+        // day 16's own threads only count when it has the process to itself
+        let partial = |num_threads| {
+            run_days(&days, None, num_threads, SolveOptions::default())
+                .iter()
+                .map(|run| (run.day, run.cpu_time_partial))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(partial(2), [(6, false), (16, true)]);
+        assert_eq!(partial(1), [(6, false), (16, false)]);
+    }
+}
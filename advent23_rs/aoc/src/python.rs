@@ -0,0 +1,69 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+// imports dayN.solver and runs its main on stdin, which prints one answer per line. the solvers
+// import aocd to download their input, a fake module stands in for it so it needn't be installed
+const DRIVER: &str = "\
+import importlib, sys, types
+aocd = types.ModuleType('aocd')
+aocd.get_data = None
+sys.modules['aocd'] = aocd
+importlib.import_module(sys.argv[1] + '.solver').main(sys.stdin.read())
+";
+
+// what the template solvers return before a day is solved
+const STUB_ANSWERS: [&str; 2] = ["142", "281"];
+
+// the advent23_py directory next to the workspace
+pub fn solutions_dir(workspace_dir: &Path) -> PathBuf {
+    workspace_dir.parent().unwrap().join("advent23_py")
+}
+
+pub fn has_solver(solutions_dir: &Path, day: u8) -> bool {
+    solutions_dir
+        .join(format!("day{day}"))
+        .join("solver.py")
+        .is_file()
+}
+
+// run the Python solution for a day, returns the answer to each part
+pub fn solve(python: &str, solutions_dir: &Path, day: u8, input: &str) -> Result<Vec<String>> {
+    let mut child = Command::new(python)
+        .current_dir(solutions_dir)
+        .args(["-c", DRIVER, &format!("day{day}")])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Unable to run {python}"))?;
+
+    // the solvers read all of stdin before printing anything, so this can't deadlock
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .context("Unable to send the input to Python")?;
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!(
+            "the Python solution failed: {}",
+            stderr.lines().last().unwrap_or("no error message")
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| line.trim().to_string())
+        .collect())
+}
+
+pub fn is_stub(answers: &[String]) -> bool {
+    answers == STUB_ANSWERS
+}
@@ -0,0 +1,22 @@
+use std::process::Command;
+
+// runs `aoc parity` on every day that has both solutions, the Python solutions need python3
+#[test]
+fn test_parity() {
+    if Command::new("python3").arg("--version").output().is_err() {
+        eprintln!("skipping, python3 is not installed");
+        return;
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("parity")
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
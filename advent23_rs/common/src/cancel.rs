@@ -0,0 +1,159 @@
+use std::{
+    error, fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+// how many steps go by between the checks tick makes
+const TICK_INTERVAL: u64 = 1 << 16;
+
+// lets a caller stop a long running solution. the solution checks the token as it goes and
+// returns Cancelled once it's been cancelled or its time is up, and reports how far it got to
+// the progress callback each time it checks. clones share the same cancelled flag
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    // when time runs out and how long the timeout was
+    deadline: Option<(Instant, Duration)>,
+    on_progress: Option<Arc<dyn Fn(u64) + Send + Sync>>,
+}
+
+impl CancellationToken {
+    // a token that is only cancelled by calling cancel
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // also cancelled once timeout has passed from now
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        CancellationToken {
+            deadline: Some((Instant::now() + timeout, timeout)),
+            ..self
+        }
+    }
+
+    // on_progress is called with the number of steps done every time the token is checked
+    pub fn with_progress(self, on_progress: impl Fn(u64) + Send + Sync + 'static) -> Self {
+        CancellationToken {
+            on_progress: Some(Arc::new(on_progress)),
+            ..self
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    // reports progress, then fails if the token has been cancelled or the timeout has passed
+    pub fn check(&self, num_steps: u64) -> Result<(), Cancelled> {
+        if let Some(on_progress) = &self.on_progress {
+            on_progress(num_steps);
+        }
+
+        if self.is_cancelled() {
+            return Err(Cancelled::Cancelled { num_steps });
+        }
+
+        match self.deadline {
+            Some((deadline, timeout)) if Instant::now() >= deadline => {
+                Err(Cancelled::TimedOut { timeout, num_steps })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // check, but only every so many steps, so it's cheap enough to call on every step of a
+    // tight loop
+    pub fn tick(&self, num_steps: u64) -> Result<(), Cancelled> {
+        if num_steps.is_multiple_of(TICK_INTERVAL) {
+            self.check(num_steps)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
+
+// why a solution stopped early, and how many steps it had done
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cancelled {
+    Cancelled { num_steps: u64 },
+    TimedOut { timeout: Duration, num_steps: u64 },
+}
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cancelled::Cancelled { num_steps } => write!(f, "cancelled after {num_steps} steps"),
+            Cancelled::TimedOut { timeout, num_steps } => {
+                write!(f, "timed out after {timeout:?} and {num_steps} steps")
+            }
+        }
+    }
+}
+
+impl error::Error for Cancelled {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_cancel() {
+        let token = CancellationToken::new();
+        assert_eq!(token.check(5), Ok(()));
+
+        // clones share the flag, so the token can be cancelled from another thread
+        let clone = token.clone();
+        std::thread::spawn(move || clone.cancel()).join().unwrap();
+
+        assert!(token.is_cancelled());
+        assert_eq!(token.check(7), Err(Cancelled::Cancelled { num_steps: 7 }));
+        // only every TICK_INTERVAL steps are checked
+        assert_eq!(token.tick(TICK_INTERVAL + 1), Ok(()));
+        assert!(token.tick(2 * TICK_INTERVAL).is_err());
+    }
+
+    #[test]
+    fn test_timeout_and_progress() {
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let token = CancellationToken::new()
+            .with_timeout(Duration::ZERO)
+            .with_progress({
+                let progress = progress.clone();
+                move |num_steps| progress.lock().unwrap().push(num_steps)
+            });
+
+        assert_eq!(
+            token.check(3),
+            Err(Cancelled::TimedOut {
+                timeout: Duration::ZERO,
+                num_steps: 3
+            })
+        );
+        assert_eq!(
+            token.check(4).unwrap_err().to_string(),
+            "timed out after 0ns and 4 steps"
+        );
+        assert_eq!(*progress.lock().unwrap(), [3, 4]);
+
+        let token = CancellationToken::new().with_timeout(Duration::from_secs(60));
+        assert_eq!(token.check(1), Ok(()));
+    }
+}
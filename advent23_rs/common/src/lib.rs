@@ -0,0 +1,133 @@
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+mod cancel;
+mod parse_error;
+
+pub use cancel::{CancellationToken, Cancelled};
+pub use parse_error::{parse_number, ParseError};
+
+// a day's puzzle, split into parsing the input and solving each part from the parsed input.
+// stdout is reserved for answers, so any diagnostics printed while solving go to stderr
+pub trait Solution {
+    // the parsed input, may borrow from the input string. it can be dumped as JSON to check what
+    // the parser made of an input
+    type Parsed<'a>: Serialize;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    // day 25 only has one puzzle
+    const HAS_PART2: bool = true;
+
+    // errors only need to point at the offending text, the caller locates them in the input
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Self::Answer1>;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Self::Answer2>;
+
+    // parts that can run for a long time or forever on some inputs override these to check
+    // cancel as they go, and give up with Cancelled once it's cancelled
+    fn part1_cancellable(
+        parsed: &Self::Parsed<'_>,
+        _cancel: &CancellationToken,
+    ) -> Result<Self::Answer1> {
+        Self::part1(parsed)
+    }
+
+    fn part2_cancellable(
+        parsed: &Self::Parsed<'_>,
+        _cancel: &CancellationToken,
+    ) -> Result<Self::Answer2> {
+        Self::part2(parsed)
+    }
+}
+
+pub fn num_parts<S: Solution>() -> u8 {
+    if S::HAS_PART2 {
+        2
+    } else {
+        1
+    }
+}
+
+// an answer formatted as a string, and how long it took to get it
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+// parse the input and run one part on it, timing each phase. the part stops early with an error
+// if cancel is cancelled while it runs
+pub fn solve<S: Solution>(part: u8, input: &str, cancel: &CancellationToken) -> Result<Solved> {
+    if part == 0 || part > num_parts::<S>() {
+        bail!("this day has no part {part}");
+    }
+
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|err| err.locate(input))?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part1_cancellable(&parsed, cancel)?.to_string(),
+        _ => S::part2_cancellable(&parsed, cancel)?.to_string(),
+    };
+    let solve_time = start.elapsed();
+
+    Ok(Solved {
+        answer,
+        parse_time,
+        solve_time,
+    })
+}
+
+// pretty printed JSON with the keys of every map sorted, so the same value always prints the same
+// way whatever order its hash maps are in
+pub fn to_json(value: &impl Serialize) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&serde_json::to_value(value)?)?)
+}
+
+// parse the input and print what the parser made of it as JSON
+pub fn dump_parsed<S: Solution>(input: &str) -> Result<String> {
+    let parsed = S::parse(input).map_err(|err| err.locate(input))?;
+    to_json(&parsed)
+}
+
+// what each day's binary does: read input.txt from the working directory and print every part
+pub fn run_main<S: Solution>() -> Result<()> {
+    let input = std::fs::read_to_string("input.txt").context("Unable to read \"input.txt\"")?;
+
+    let parsed = S::parse(&input).map_err(|err| err.locate(&input))?;
+
+    println!("{}", S::part1(&parsed)?);
+    if S::HAS_PART2 {
+        println!("{}", S::part2(&parsed)?);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_to_json() -> Result<()> {
+        let map = HashMap::from([("b", vec![2, 3]), ("c", vec![]), ("a", vec![1])]);
+
+        assert_eq!(
+            to_json(&map)?,
+            "{\n  \"a\": [\n    1\n  ],\n  \"b\": [\n    2,\n    3\n  ],\n  \"c\": []\n}"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use grid::GridError;
+use std::{error, fmt, str::FromStr};
+
+// an error in the puzzle input, pointing at the text that could not be parsed
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    // 1-indexed, 0 while the position in the input is not known yet
+    pub line: usize,
+    // 1-indexed byte offset into the line
+    pub column: usize,
+    pub text: String,
+    // what was expected instead of text
+    pub reason: String,
+    // where text starts in memory, so its line and column can be found once the whole input
+    // is known. This lets parsers that only see part of a line still report a precise position
+    text_addr: usize,
+}
+
+impl ParseError {
+    // text must be a slice of the input for locate to find its position
+    pub fn new(text: &str, reason: impl Into<String>) -> Self {
+        ParseError::from_bytes(text.as_bytes(), reason)
+    }
+
+    pub fn from_bytes(text: &[u8], reason: impl Into<String>) -> Self {
+        ParseError {
+            line: 0,
+            column: 0,
+            text: String::from_utf8_lossy(text).into_owned(),
+            reason: reason.into(),
+            text_addr: text.as_ptr() as usize,
+        }
+    }
+
+    // an error at an already known position
+    pub fn at(line: usize, column: usize, text: &str, reason: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.into(),
+            text_addr: 0,
+        }
+    }
+
+    // an error about the input as a whole, with no position to point at
+    pub fn message(reason: impl Into<String>) -> Self {
+        ParseError::at(0, 0, "", reason)
+    }
+
+    // an error for something missing right after text, for example a line that ends too early
+    pub fn missing_after(text: &str, reason: impl Into<String>) -> Self {
+        ParseError::new(&text[text.len()..], reason)
+    }
+
+    // fill in the line and column by finding where text was in the input
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.line != 0 || self.text_addr < start || self.text_addr > start + input.len() {
+            return self;
+        }
+
+        let before = &input.as_bytes()[..self.text_addr - start];
+        let line_start = before
+            .iter()
+            .rposition(|&c| c == b'\n')
+            .map_or(0, |i| i + 1);
+
+        self.line = before.iter().filter(|&&c| c == b'\n').count() + 1;
+        self.column = before.len() - line_start + 1;
+        self
+    }
+}
+
+// text_addr is only a hint for locate, two errors are the same if they say the same thing
+impl PartialEq for ParseError {
+    fn eq(&self, other: &Self) -> bool {
+        self.line == other.line
+            && self.column == other.column
+            && self.text == other.text
+            && self.reason == other.reason
+    }
+}
+
+impl Eq for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line != 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+        if self.text.is_empty() {
+            write!(f, "{}", self.reason)
+        } else {
+            write!(f, "{}, found \"{}\"", self.reason, self.text.escape_debug())
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+impl From<GridError> for ParseError {
+    fn from(err: GridError) -> Self {
+        match err {
+            GridError::Empty => ParseError::at(1, 1, "", "expected a grid, the input is empty"),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => ParseError::at(
+                line,
+                found.min(expected) + 1,
+                "",
+                format!("expected a row of length {expected}, found length {found}"),
+            ),
+            GridError::InvalidCell { line, column, byte } => ParseError::at(
+                line,
+                column,
+                &(byte as char).to_string(),
+                "unexpected character in grid",
+            ),
+        }
+    }
+}
+
+// parse text as a number, with an error pointing at text if it is not one
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, "expected a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "1 2 3\n4 x 6\n";
+        let bad = input.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+
+        let err = parse_number::<u32>(bad).unwrap_err().locate(input);
+
+        assert_eq!(err, ParseError::at(2, 3, "x", "expected a number"));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_locate_outside_input() {
+        let input = String::from("1 2 3");
+        let err = ParseError::new("x", "expected a number").locate(&input);
+
+        assert_eq!(err.line, 0);
+        assert_eq!(err.to_string(), "expected a number, found \"x\"");
+    }
+
+    #[test]
+    fn test_missing_after() {
+        let input = "seeds:";
+        let err = ParseError::missing_after(input, "expected a list of seeds").locate(input);
+
+        assert_eq!(err.line, 1);
+        assert_eq!(err.column, 7);
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    convert::Infallible,
+    hash::Hash,
+};
+
+// where the states initial, step(initial), step(step(initial)), ... start repeating
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T> {
+    // the number of steps taken before the first state on the cycle
+    pub prefix_len: usize,
+    // the number of steps it takes to get back to a state on the cycle
+    pub period: usize,
+    // the first state on the cycle, the state after prefix_len steps
+    pub start: T,
+}
+
+impl<T: Clone> Cycle<T> {
+    // the smallest number of steps that ends up in the same state as taking n steps
+    pub fn equivalent_steps(&self, n: usize) -> usize {
+        if n < self.prefix_len {
+            n
+        } else {
+            self.prefix_len + (n - self.prefix_len) % self.period
+        }
+    }
+
+    // the state after n steps, taking fewer than prefix_len + period steps
+    pub fn state_at(&self, initial: &T, n: usize, mut step: impl FnMut(&mut T)) -> T {
+        let (mut state, num_steps) = if n < self.prefix_len {
+            (initial.clone(), n)
+        } else {
+            (self.start.clone(), (n - self.prefix_len) % self.period)
+        };
+
+        for _ in 0..num_steps {
+            step(&mut state);
+        }
+
+        state
+    }
+}
+
+// brent's algorithm, only keeps 2 states around at a time but takes up to about 3 times as many
+// steps as there are states before the sequence repeats. never returns if it doesn't repeat
+pub fn brent<T: Clone + PartialEq>(initial: &T, mut step: impl FnMut(&mut T)) -> Cycle<T> {
+    try_brent(initial, |state| {
+        step(state);
+        Ok::<_, Infallible>(())
+    })
+    .unwrap_or_else(|never| match never {})
+}
+
+// brent for steps that can fail, it gives up on the first error. a step that checks a
+// cancellation token or a step limit can stop a sequence that takes too long to repeat
+pub fn try_brent<T: Clone + PartialEq, E>(
+    initial: &T,
+    mut step: impl FnMut(&mut T) -> Result<(), E>,
+) -> Result<Cycle<T>, E> {
+    // find the period, by moving the tortoise up to the hare at every power of 2
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    step(&mut hare)?;
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        step(&mut hare)?;
+        period += 1;
+    }
+
+    // with the hare a period ahead, both meet at the first state on the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        step(&mut hare)?;
+    }
+
+    let mut prefix_len = 0;
+    while tortoise != hare {
+        step(&mut tortoise)?;
+        step(&mut hare)?;
+        prefix_len += 1;
+    }
+
+    Ok(Cycle {
+        prefix_len,
+        period,
+        start: tortoise,
+    })
+}
+
+// remembers every state until one repeats, so each state is only stepped from once. best when
+// steps are expensive and there aren't many states. never returns if it doesn't repeat
+pub fn hashed<T: Clone + Eq + Hash>(initial: &T, mut step: impl FnMut(&mut T)) -> Cycle<T> {
+    let mut seen = HashMap::new();
+    let mut state = initial.clone();
+
+    for i in 0.. {
+        match seen.entry(state.clone()) {
+            Entry::Occupied(entry) => {
+                return Cycle {
+                    prefix_len: *entry.get(),
+                    period: i - entry.get(),
+                    start: state,
+                };
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(i);
+            }
+        }
+
+        step(&mut state);
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7 -> 3
+    fn step(x: &mut u32) {
+        *x = if *x == 7 { 3 } else { *x + 1 };
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let expected = Cycle {
+            prefix_len: 3,
+            period: 5,
+            start: 3,
+        };
+
+        assert_eq!(brent(&0, step), expected);
+        assert_eq!(hashed(&0, step), expected);
+
+        assert_eq!(brent(&5, step).prefix_len, 0);
+        assert_eq!(hashed(&5, step).start, 5);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let cycle = brent(&10, |x: &mut u32| *x = (*x).saturating_sub(3));
+
+        assert_eq!(
+            cycle,
+            Cycle {
+                prefix_len: 4,
+                period: 1,
+                start: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_brent() {
+        let infallible = |x: &mut u32| {
+            step(x);
+            Ok::<_, ()>(())
+        };
+        assert_eq!(try_brent(&0, infallible), Ok(brent(&0, step)));
+
+        // gives up once the steps fail
+        let mut num_steps = 0;
+        let result = try_brent(&0u64, |x| {
+            num_steps += 1;
+            if num_steps > 100 {
+                return Err(num_steps);
+            }
+            *x += 1;
+            Ok(())
+        });
+        assert_eq!(result, Err(101));
+    }
+
+    #[test]
+    fn test_state_at() {
+        // a longer and less regular sequence
+        let step = |x: &mut u64| *x = (*x * *x + 1) % 1009;
+        let cycle = brent(&2, step);
+        assert_eq!(cycle, hashed(&2, step));
+
+        let mut state = 2;
+        for n in 0..3000 {
+            assert_eq!(cycle.state_at(&2, n, step), state);
+            step(&mut state);
+        }
+
+        let n = cycle.equivalent_steps(1_000_000_000);
+        assert!(n < cycle.prefix_len + cycle.period);
+        assert_eq!(
+            cycle.state_at(&2, n, step),
+            cycle.state_at(&2, 1_000_000_000, step)
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+
+// the spelled out digits that count in part 2
+pub const DIGIT_WORDS: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+// the digit starting at the beginning of text, either as a digit or as one of the words
+fn digit_at(text: &[u8], words: &[(&str, u32)]) -> Option<u32> {
+    match text.first() {
+        Some(c) if c.is_ascii_digit() => Some((c - b'0') as u32),
+        _ => words
+            .iter()
+            .find(|(word, _)| text.starts_with(word.as_bytes()))
+            .map(|&(_, digit)| digit),
+    }
+}
+
+// the first digit and the last digit of the line as a 2 digit number. every position is checked
+// on its own, so words that share letters are all found, like the 8 and the 2 in "eightwo"
+pub fn calibration_value(line: &str, words: &[(&str, u32)]) -> Option<u32> {
+    let line = line.as_bytes();
+    let first = (0..line.len()).find_map(|i| digit_at(&line[i..], words))?;
+    let last = (0..line.len())
+        .rev()
+        .find_map(|i| digit_at(&line[i..], words))?;
+
+    Some(first * 10 + last)
+}
+
+pub fn sum_calibration_values(lines: &[&str], words: &[(&str, u32)]) -> Result<u32> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            calibration_value(line, words)
+                .with_context(|| format!("line {}: no digit in \"{line}\"", i + 1))
+        })
+        .sum()
+}
+
+pub fn part1(lines: &[&str]) -> Result<u32> {
+    sum_calibration_values(lines, &[])
+}
+
+pub fn part2(lines: &[&str]) -> Result<u32> {
+    sum_calibration_values(lines, &DIGIT_WORDS)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = Vec<&'a str>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    // which lines have a digit depends on the part, so the parts check that
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(lines: &Self::Parsed<'_>) -> Result<u32> {
+        part1(lines)
+    }
+
+    fn part2(lines: &Self::Parsed<'_>) -> Result<u32> {
+        part2(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_A: &str = "1abc2\n\
+                                pqr3stu8vwx\n\
+                                a1b2c3d4e5f\n\
+                                treb7uchet";
+
+    const TEST_INPUT_B: &str = "two1nine\n\
+                                eightwothree\n\
+                                abcone2threexyz\n\
+                                xtwone3four\n\
+                                4nineeightseven2\n\
+                                zoneight234\n\
+                                7pqrstsixteen";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Day1::parse(TEST_INPUT_A).unwrap()).unwrap(), 142);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Day1::parse(TEST_INPUT_B).unwrap()).unwrap(), 281);
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        assert_eq!(calibration_value("eightwo", &DIGIT_WORDS), Some(82));
+        assert_eq!(calibration_value("xoneightx", &DIGIT_WORDS), Some(18));
+        assert_eq!(calibration_value("twone", &DIGIT_WORDS), Some(21));
+        assert_eq!(calibration_value("seven", &DIGIT_WORDS), Some(77));
+    }
+
+    #[test]
+    fn test_word_table() {
+        let words = [("uno", 1), ("dos", 2), ("tres", 3)];
+
+        assert_eq!(calibration_value("unodostres", &words), Some(13));
+        assert_eq!(calibration_value("xdosx", &words), Some(22));
+        assert_eq!(calibration_value("one", &words), None);
+    }
+
+    #[test]
+    fn test_missing_digit() {
+        let err = part1(&["1abc2", "eightwo"]).unwrap_err();
+
+        assert_eq!(err.to_string(), "line 2: no digit in \"eightwo\"");
+    }
+}
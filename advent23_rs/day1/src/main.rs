@@ -0,0 +1,5 @@
+use day1::Day1;
+
+fn main() -> anyhow::Result<()> {
+    common::run_main::<Day1>()
+}
@@ -0,0 +1,367 @@
+use anyhow::Result;
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Position, Turn};
+use image::Image;
+use serde::Serialize;
+
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy, Serialize)]
+pub enum Tile {
+    NorthSouth = b'|',
+    EastWest = b'-',
+    NorthEast = b'L',
+    NorthWest = b'J',
+    SouthWest = b'7',
+    SouthEast = b'F',
+    Ground = b'.',
+    Start = b'S',
+}
+
+impl Tile {
+    fn connects_to(&self, dir: Direction) -> bool {
+        match self.dirs() {
+            Some([d1, d2]) => dir == d1 || dir == d2,
+            None => false,
+        }
+    }
+
+    fn dirs(&self) -> Option<[Direction; 2]> {
+        use Direction::*;
+
+        match *self {
+            Tile::NorthSouth => Some([North, South]),
+            Tile::EastWest => Some([East, West]),
+            Tile::NorthEast => Some([North, East]),
+            Tile::NorthWest => Some([North, West]),
+            Tile::SouthWest => Some([South, West]),
+            Tile::SouthEast => Some([South, East]),
+            Tile::Ground => None,
+            Tile::Start => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            b'|' => Ok(Tile::NorthSouth),
+            b'-' => Ok(Tile::EastWest),
+            b'L' => Ok(Tile::NorthEast),
+            b'J' => Ok(Tile::NorthWest),
+            b'7' => Ok(Tile::SouthWest),
+            b'F' => Ok(Tile::SouthEast),
+            b'.' => Ok(Tile::Ground),
+            b'S' => Ok(Tile::Start),
+            _ => Err(value),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(u8)]
+enum TileInfo {
+    Enclosed,
+    InLoop,
+    // NotEnclosed,
+}
+
+// the first pipe after the start, None if no pipe connects to it
+fn find_intitial_pipe_state(puzzle_input: &Grid<Tile>) -> Option<PipeState> {
+    let start = puzzle_input.position(|&c| c == Tile::Start)?;
+
+    Direction::iter().find_map(|dir| {
+        let (row, col) = puzzle_input
+            .try_move(start, dir)
+            .filter(|&next| puzzle_input[next].connects_to(dir.opposite()))?;
+
+        Some(PipeState { row, col, dir })
+    })
+}
+
+// parse checks there is one
+fn get_intitial_pipe_state(puzzle_input: &Grid<Tile>) -> PipeState {
+    find_intitial_pipe_state(puzzle_input).unwrap()
+}
+
+// follows the pipes from the start to make sure they lead back to it, so the loop can be walked
+// without checking every step
+fn check_loop(puzzle_input: &Grid<Tile>) -> Result<(), ParseError> {
+    let Some(mut state) = find_intitial_pipe_state(puzzle_input) else {
+        return Err(ParseError::message(
+            "expected a pipe connecting to the start tile 'S'",
+        ));
+    };
+
+    loop {
+        let position = (state.row, state.col);
+        let tile = puzzle_input[position];
+        if tile == Tile::Start {
+            return Ok(());
+        }
+
+        let [d1, d2] = tile.dirs().unwrap();
+        state.dir = if d1 == state.dir.opposite() { d2 } else { d1 };
+
+        match puzzle_input.try_move(position, state.dir).filter(|&next| {
+            puzzle_input[next] == Tile::Start
+                || puzzle_input[next].connects_to(state.dir.opposite())
+        }) {
+            Some(next) => (state.row, state.col) = next,
+            None => {
+                let (row, col) = position;
+                return Err(ParseError::at(
+                    row + 1,
+                    col + 1,
+                    &char::from(tile as u8).to_string(),
+                    "expected the pipe to lead to another pipe on the loop",
+                ));
+            }
+        }
+    }
+}
+
+struct PipeIterator<'a> {
+    puzzle_input: &'a Grid<Tile>,
+    state: PipeState,
+    done: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PipeState {
+    row: usize,
+    col: usize,
+    dir: Direction,
+}
+
+fn get_pipe_iterator(puzzle_input: &Grid<Tile>, initial_state: PipeState) -> PipeIterator<'_> {
+    PipeIterator {
+        puzzle_input,
+        state: initial_state,
+        done: false,
+    }
+}
+
+impl Iterator for PipeIterator<'_> {
+    type Item = PipeState;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let state = &mut self.state;
+
+        if self.puzzle_input[(state.row, state.col)] == Tile::Start {
+            self.done = true;
+            return Some(*state);
+        }
+
+        // get next direction
+        let [d1, d2] = self.puzzle_input[(state.row, state.col)].dirs().unwrap();
+
+        state.dir = if d1 == state.dir.opposite() { d2 } else { d1 };
+
+        let result = *state;
+
+        (state.row, state.col) = self
+            .puzzle_input
+            .try_move((state.row, state.col), state.dir)
+            .unwrap();
+
+        Some(result)
+    }
+}
+
+pub fn part1(puzzle_input: &Grid<Tile>) -> usize {
+    // first find the position of the S
+    // go 1 step in a direction
+    // follow the pipe until reaching S
+    // count the number of time taken to reach S
+    // divide by 2
+    let num_steps = get_pipe_iterator(puzzle_input, get_intitial_pipe_state(puzzle_input)).count();
+
+    num_steps / 2
+}
+
+// uses an explicit stack, the enclosed area of a big loop is too deep to recurse through
+fn flood_fill(tile_info_arr: &mut Grid<Option<TileInfo>>, position: Position) {
+    let mut stack = vec![position];
+
+    while let Some(position) = stack.pop() {
+        for dir in Direction::iter() {
+            let Some(next) = tile_info_arr.try_move(position, dir) else {
+                continue;
+            };
+
+            if tile_info_arr[next].is_none() {
+                tile_info_arr[next] = Some(TileInfo::Enclosed);
+                stack.push(next);
+            }
+        }
+    }
+}
+
+// which tiles are on the loop and which are enclosed by it
+fn classify_tiles(puzzle_input: &Grid<Tile>) -> Grid<Option<TileInfo>> {
+    let mut tile_info_arr =
+        Grid::filled_with(None, puzzle_input.num_rows(), puzzle_input.num_columns());
+
+    // follow main loop and count lefts vs rights
+    let initial_state = get_intitial_pipe_state(puzzle_input);
+
+    let mut prev_dir = initial_state.dir;
+
+    let mut num_lefts = 0;
+    let mut num_rights = 0;
+
+    for pipe_state in get_pipe_iterator(puzzle_input, initial_state) {
+        match pipe_state.dir.turn_since(prev_dir) {
+            Turn::Right => num_rights += 1,
+            Turn::Left => num_lefts += 1,
+            _ => (),
+        }
+
+        tile_info_arr[(pipe_state.row, pipe_state.col)] = Some(TileInfo::InLoop);
+
+        prev_dir = pipe_state.dir;
+    }
+
+    let inside_turn = if num_rights > num_lefts {
+        Turn::Right
+    } else {
+        Turn::Left
+    };
+
+    let mut check_next_to_inside = |row, col, dir: Direction| {
+        let inside = tile_info_arr
+            .try_move((row, col), dir.turn(inside_turn))
+            .unwrap();
+
+        if tile_info_arr[inside].is_none() {
+            tile_info_arr[inside] = Some(TileInfo::Enclosed);
+        }
+    };
+
+    // follow main loop again with same starts, but this time
+    // turn inside_turn every step and set it in the array
+    // tile_info_arr
+    prev_dir = initial_state.dir;
+    for pipe_state in get_pipe_iterator(puzzle_input, initial_state) {
+        // (irow, icol) is on the inside of the loop if not on the loop
+        check_next_to_inside(pipe_state.row, pipe_state.col, prev_dir);
+
+        prev_dir = pipe_state.dir;
+
+        // go againe
+        check_next_to_inside(pipe_state.row, pipe_state.col, pipe_state.dir);
+    }
+
+    for position in tile_info_arr.positions() {
+        if matches!(tile_info_arr[position], Some(TileInfo::Enclosed)) {
+            flood_fill(&mut tile_info_arr, position);
+        }
+    }
+
+    tile_info_arr
+}
+
+pub fn part2(puzzle_input: &Grid<Tile>) -> usize {
+    classify_tiles(puzzle_input)
+        .iter()
+        .filter(|cell| matches!(cell, Some(TileInfo::Enclosed)))
+        .count()
+}
+
+// the loop in white and the tiles it encloses in green, the pipes that aren't part of it are grey
+pub fn render(puzzle_input: &Grid<Tile>) -> Image {
+    let tile_info_arr = classify_tiles(puzzle_input);
+
+    puzzle_input.map_with_position(|position, &tile| match tile_info_arr[position] {
+        Some(TileInfo::InLoop) => image::WHITE,
+        Some(TileInfo::Enclosed) => [60, 180, 75],
+        None if tile == Tile::Ground => image::BLACK,
+        None => [80, 80, 80],
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Grid<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let grid = Grid::parse(input)?;
+
+        if grid.position(|&tile| tile == Tile::Start).is_none() {
+            return Err(ParseError::message(
+                "expected the grid to contain a start tile 'S'",
+            ));
+        }
+        check_loop(&grid)?;
+
+        Ok(grid)
+    }
+
+    fn part1(puzzle_input: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part1(puzzle_input))
+    }
+
+    fn part2(puzzle_input: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part2(puzzle_input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_A: &str = ".F----7F7F7F7F-7....\n\
+                                .|F--7||||||||FJ....\n\
+                                .||.FJ||||||||L7....\n\
+                                FJL7L7LJLJ||LJ.L-7..\n\
+                                L--J.L7...LJS7F-7L7.\n\
+                                ....F-J..F7FJ|L7L7L7\n\
+                                ....L7.F7||L7|.L7L7|\n\
+                                .....|FJLJ|FJ|F7|.LJ\n\
+                                ....FJL-7.||.||||...\n\
+                                ....L---J.LJ.LJLJ...";
+
+    fn test_part_2(input_as_str: &str, answer: usize) {
+        let puzzle_input = Grid::parse(input_as_str).unwrap();
+
+        assert_eq!(part2(&puzzle_input), answer);
+    }
+
+    #[test]
+    fn stu_test() {
+        test_part_2(TEST_INPUT_A, 8);
+    }
+
+    #[test]
+    fn test_broken_loops() {
+        let parse = |input| Day10::parse(input).map(|_| ());
+
+        assert_eq!(parse("S-7\n|.|\nL-J\n..."), Ok(()));
+        assert_eq!(
+            parse("S"),
+            Err(ParseError::message(
+                "expected a pipe connecting to the start tile 'S'"
+            ))
+        );
+
+        // the 7 leads down to the ground instead of back round to the start
+        assert_eq!(
+            parse("S-7\n|..\nL-J"),
+            Err(ParseError::at(
+                1,
+                3,
+                "7",
+                "expected the pipe to lead to another pipe on the loop"
+            ))
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use common::{ParseError, Solution};
+// (row, col) position
+struct Galaxy(usize, usize);
+
+pub fn find_sum_of_dists(puzzle_input: &[&[u8]], expansion_factor: usize) -> usize {
+    let mut row_expands = Vec::with_capacity(puzzle_input.len());
+    let mut num_empty = 0;
+
+    for line in puzzle_input.iter() {
+        if line.iter().all(|&c| c == b'.') {
+            num_empty += expansion_factor - 1;
+        }
+        row_expands.push(num_empty);
+    }
+
+    let mut col_expands = Vec::with_capacity(puzzle_input[0].len());
+    let mut num_empty = 0;
+
+    for col in 0..puzzle_input[0].len() {
+        if puzzle_input.iter().all(|row| row[col] == b'.') {
+            num_empty += expansion_factor - 1;
+        }
+        col_expands.push(num_empty);
+    }
+
+    let mut galaxies = Vec::new();
+
+    for (row, line) in puzzle_input.iter().enumerate() {
+        for (col, &cell) in line.iter().enumerate() {
+            if cell == b'#' {
+                galaxies.push(Galaxy(row + row_expands[row], col + col_expands[col]));
+            }
+        }
+    }
+
+    let mut result = 0;
+    for (i, Galaxy(row1, col1)) in galaxies.iter().enumerate() {
+        for Galaxy(row2, col2) in galaxies[i + 1..].iter() {
+            result += row1.abs_diff(*row2);
+
+            result += col1.abs_diff(*col2);
+        }
+    }
+
+    result
+}
+
+// the image of galaxies ('#') and empty space ('.'), one row per line
+pub fn parse_input(input: &str) -> Result<Vec<&[u8]>, ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+    if lines.is_empty() {
+        return Err(ParseError::message("expected an image, the input is empty"));
+    }
+
+    for line in &lines {
+        if line.len() != lines[0].len() {
+            return Err(ParseError::new(
+                line,
+                format!("expected a row of length {}", lines[0].len()),
+            ));
+        }
+        if let Some(i) = line.bytes().position(|c| c != b'.' && c != b'#') {
+            return Err(ParseError::from_bytes(
+                &line.as_bytes()[i..=i],
+                "expected '.' or '#'",
+            ));
+        }
+    }
+
+    Ok(lines.into_iter().map(str::as_bytes).collect())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Vec<&'a [u8]>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(puzzle_input: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(find_sum_of_dists(puzzle_input, 2))
+    }
+
+    fn part2(puzzle_input: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(find_sum_of_dists(puzzle_input, 1000000))
+    }
+}
@@ -0,0 +1,263 @@
+use anyhow::Result;
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Position};
+use image::Image;
+use serde::Serialize;
+use std::iter;
+
+use num::FromPrimitive;
+use num_derive::FromPrimitive;
+
+#[derive(Debug, FromPrimitive, Clone, Hash, PartialEq, Eq, Serialize)]
+#[repr(u8)]
+enum Cell {
+    Round = b'O',
+    Cube = b'#',
+    Empty = b'.',
+}
+
+impl TryFrom<u8> for Cell {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        Cell::from_u8(value).ok_or(value)
+    }
+}
+
+// the order of tilts in one spin cycle
+const SPIN_CYCLE: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+pub struct Platform(Grid<Cell>);
+
+// a tilt done a line at a time, starting from the line along the edge the rocks roll towards.
+// each round rock rolls as far as it can once its line is reached
+struct Tilt {
+    direction: Direction,
+    num_done: usize,
+    // for each lane the rocks roll along, how many lines from the edge the next rock stops
+    stops: Vec<usize>,
+}
+
+impl Tilt {
+    fn new(platform: &Platform, direction: Direction) -> Self {
+        let num_lanes = match direction {
+            Direction::North | Direction::South => platform.0.num_columns(),
+            Direction::East | Direction::West => platform.0.num_rows(),
+        };
+
+        Tilt {
+            direction,
+            num_done: 0,
+            stops: vec![0; num_lanes],
+        }
+    }
+
+    // the cell in lane that is i lines from the edge
+    fn position(&self, platform: &Platform, lane: usize, i: usize) -> Position {
+        match self.direction {
+            Direction::North => (i, lane),
+            Direction::South => (platform.0.num_rows() - 1 - i, lane),
+            Direction::West => (lane, i),
+            Direction::East => (lane, platform.0.num_columns() - 1 - i),
+        }
+    }
+
+    // rolls the round rocks on the next line, false once every line is done
+    fn step(&mut self, platform: &mut Platform) -> bool {
+        let num_lines = match self.direction {
+            Direction::North | Direction::South => platform.0.num_rows(),
+            Direction::East | Direction::West => platform.0.num_columns(),
+        };
+        if self.num_done == num_lines {
+            return false;
+        }
+
+        let i = self.num_done;
+        for lane in 0..self.stops.len() {
+            let position = self.position(platform, lane, i);
+            match platform.0[position] {
+                Cell::Round => {
+                    platform.0[position] = Cell::Empty;
+                    let stop = self.position(platform, lane, self.stops[lane]);
+                    platform.0[stop] = Cell::Round;
+                    self.stops[lane] += 1;
+                }
+                Cell::Cube => self.stops[lane] = i + 1,
+                Cell::Empty => (),
+            }
+        }
+
+        self.num_done += 1;
+        true
+    }
+}
+
+impl Platform {
+    fn tilt(&mut self, direction: Direction) {
+        let mut tilt = Tilt::new(self, direction);
+        while tilt.step(self) {}
+    }
+
+    // round rocks in orange, cube rocks in grey
+    pub fn render(&self) -> Image {
+        self.0.map(|cell| match cell {
+            Cell::Round => [230, 140, 40],
+            Cell::Cube => [110, 110, 110],
+            Cell::Empty => image::BLACK,
+        })
+    }
+
+    fn total_load(&self) -> usize {
+        self.0
+            .columns_iter()
+            .flat_map(|column| column.rev().enumerate())
+            .filter_map(|(i, cell)| {
+                if matches!(cell, Cell::Round) {
+                    Some(i + 1)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+}
+
+impl TryFrom<&str> for Platform {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, ParseError> {
+        Ok(Platform(Grid::parse(input)?))
+    }
+}
+
+// the platform after part 1 tilts it north
+pub fn tilted_north(mut platform: Platform) -> Platform {
+    platform.tilt(Direction::North);
+    platform
+}
+
+fn spin(platform: &mut Platform) {
+    for direction in SPIN_CYCLE {
+        platform.tilt(direction);
+    }
+}
+
+// the platform after all of part 2's spin cycles
+pub fn spun(platform: Platform) -> Platform {
+    const NUM_CYCLES: usize = 1000000000;
+
+    // brent's algorithm only keeps a couple of platforms around instead of one per spin cycle
+    cycle::brent(&platform, spin).state_at(&platform, NUM_CYCLES, spin)
+}
+
+// the rocks rolling north a row per frame, from the platform to tilted_north(platform)
+pub fn tilt_frames(mut platform: Platform) -> impl Iterator<Item = Image> {
+    let mut tilt = Tilt::new(&platform, Direction::North);
+
+    iter::once(platform.render()).chain(iter::from_fn(move || {
+        tilt.step(&mut platform).then(|| platform.render())
+    }))
+}
+
+// a frame after every tilt of part 2's spin cycles, until the platform starts repeating
+pub fn spin_frames(platform: Platform) -> impl Iterator<Item = Image> {
+    let cycle = cycle::brent(&platform, spin);
+    let num_tilts = SPIN_CYCLE.len() * (cycle.prefix_len + cycle.period);
+
+    iter::once(platform.render()).chain(SPIN_CYCLE.into_iter().cycle().take(num_tilts).scan(
+        platform,
+        |platform, direction| {
+            platform.tilt(direction);
+            Some(platform.render())
+        },
+    ))
+}
+
+pub fn part1(platform: Platform) -> usize {
+    tilted_north(platform).total_load()
+}
+
+pub fn part2(platform: Platform) -> usize {
+    spun(platform).total_load()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = Platform;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Platform::try_from(input)
+    }
+
+    fn part1(platform: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part1(platform.clone()))
+    }
+
+    fn part2(platform: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part2(platform.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    const TEST_INPUT: &str = "O....#....\n\
+                              O.OO#....#\n\
+                              .....##...\n\
+                              OO.#O....O\n\
+                              .O.....O#.\n\
+                              O.#..O.#.#\n\
+                              ..O..#O..O\n\
+                              .......O..\n\
+                              #....###..\n\
+                              #OO..#....";
+
+    #[test]
+    fn test_part_1() -> Result<()> {
+        let puzzle_input = TEST_INPUT.try_into().context("Unable to parse platform")?;
+
+        assert_eq!(part1(puzzle_input), 136);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_2() -> Result<()> {
+        let puzzle_input = TEST_INPUT.try_into().context("Unable to parse platform")?;
+
+        assert_eq!(part2(puzzle_input), 64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_frames() -> Result<()> {
+        let platform = Platform::try_from(TEST_INPUT)?;
+
+        // a frame after each row, ending up where part 1's tilt does
+        let frames = tilt_frames(platform.clone()).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 1 + 10);
+        assert_eq!(frames[0], platform.render());
+        assert_eq!(
+            frames.last(),
+            Some(&tilted_north(platform.clone()).render())
+        );
+
+        // the example repeats after 3 spin cycles with a period of 7
+        let frames = spin_frames(platform).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 1 + 4 * (3 + 7));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+use serde::Serialize;
+use std::array;
+
+#[derive(Serialize)]
+pub enum Instruction<'a> {
+    Equals { label: &'a [u8], focal_length: u8 },
+    Dash { label: &'a [u8] },
+}
+
+impl<'a> TryFrom<&'a [u8]> for Instruction<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a [u8]) -> Result<Self, ParseError> {
+        if let Some(label) = s.strip_suffix(b"-") {
+            Ok(Instruction::Dash { label })
+        } else {
+            let instruction_as_str = std::str::from_utf8(s)
+                .map_err(|_| ParseError::from_bytes(s, "expected an ASCII instruction"))?;
+            let (label_as_str, focal_length_as_str) =
+                instruction_as_str.split_once('=').ok_or_else(|| {
+                    ParseError::new(
+                        instruction_as_str,
+                        "expected an instruction like \"rn=1\" or \"cm-\"",
+                    )
+                })?;
+
+            Ok(Instruction::Equals {
+                label: label_as_str.as_bytes(),
+                focal_length: parse_number(focal_length_as_str)?,
+            })
+        }
+    }
+}
+
+struct HashMapEntry<'a> {
+    label: &'a [u8],
+    focal_length: u8,
+}
+
+fn holiday_hash(s: &[u8]) -> u8 {
+    let mut result = 0;
+
+    for c in s {
+        result += *c as u32;
+        result *= 17;
+        result %= 256;
+    }
+
+    result as u8
+}
+
+pub fn part1(puzzle_input: &[u8]) -> u64 {
+    puzzle_input
+        .split(|&c| c == b',')
+        .map(|s| holiday_hash(s) as u64)
+        .sum()
+}
+
+pub fn parse_instructions(puzzle_input: &[u8]) -> Result<Vec<Instruction<'_>>, ParseError> {
+    puzzle_input
+        .split(|&c| c == b',')
+        .map(Instruction::try_from)
+        .collect()
+}
+
+pub fn part2(instructions: &[Instruction]) -> usize {
+    let mut hashmap: [Vec<HashMapEntry>; 256] = array::from_fn(|_| Vec::new());
+
+    for instruction in instructions {
+        match *instruction {
+            Instruction::Equals {
+                label,
+                focal_length,
+            } => {
+                // add label with focal length to the list,
+                // or modify the label if it already there
+                let list = &mut hashmap[holiday_hash(label) as usize];
+
+                let mut found = false;
+
+                for entry in list.iter_mut() {
+                    if label == entry.label {
+                        entry.focal_length = focal_length;
+                        found = true;
+                        break;
+                    }
+                }
+
+                if !found {
+                    list.push(HashMapEntry {
+                        label,
+                        focal_length,
+                    })
+                }
+            }
+            Instruction::Dash { label } => {
+                // remove the element from the list if it exists
+                let list = &mut hashmap[holiday_hash(label) as usize];
+                if let Some(i) = list.iter().position(|entry| label == entry.label) {
+                    list.remove(i);
+                }
+            }
+        }
+    }
+
+    hashmap
+        .into_iter()
+        .enumerate()
+        .map(|(box_num, list)| {
+            (box_num + 1)
+                * list
+                    .into_iter()
+                    .enumerate()
+                    .map(|(slot_num, HashMapEntry { focal_length, .. })| {
+                        (slot_num + 1) * (focal_length as usize)
+                    })
+                    .sum::<usize>()
+        })
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    // part 1 hashes the raw steps, part 2 follows them as instructions
+    type Parsed<'a> = (&'a [u8], Vec<Instruction<'a>>);
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        // the steps are all on one line, ignore the newline at the end of it
+        let puzzle_input = input.trim_end_matches(['\r', '\n']).as_bytes();
+
+        Ok((puzzle_input, parse_instructions(puzzle_input)?))
+    }
+
+    fn part1((puzzle_input, _): &Self::Parsed<'_>) -> Result<u64> {
+        Ok(part1(puzzle_input))
+    }
+
+    fn part2((_, instructions): &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part2(instructions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &[u8] = b"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn test_holiday_hash() {
+        assert_eq!(holiday_hash(b"HASH"), 52);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(TEST_INPUT), 1320);
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&parse_instructions(TEST_INPUT)?), 145);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,331 @@
+use std::{collections::HashSet, iter, thread};
+
+use anyhow::Result;
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Position};
+use image::Image;
+use num::FromPrimitive;
+use num_derive::FromPrimitive;
+use serde::Serialize;
+
+#[derive(Debug, FromPrimitive, Clone, Serialize)]
+#[repr(u8)]
+pub enum Tile {
+    NorthSouthSplitter = b'|',
+    EastWestSplitter = b'-',
+    SlashMirror = b'/',
+    BackSlashMirror = b'\\',
+    Empty = b'.',
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        Tile::from_u8(value).ok_or(value)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Ok(Grid::parse(input)?)
+}
+
+enum GetSuccessorsResult<T> {
+    Zero,
+    One(T),
+    Two(T, T),
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+struct State {
+    row: usize,
+    column: usize,
+    direction: Direction,
+}
+
+impl State {
+    fn new(row: usize, column: usize, direction: Direction) -> Self {
+        State {
+            row,
+            column,
+            direction,
+        }
+    }
+
+    fn get_successors(&self, puzzle_input: &Grid<Tile>) -> GetSuccessorsResult<Self> {
+        let &State {
+            row,
+            column,
+            direction: prev_dir,
+        } = self;
+
+        let mut next_dir = prev_dir;
+        let mut next_dir2 = None;
+
+        {
+            use Direction::*;
+
+            match puzzle_input[(row, column)] {
+                Tile::NorthSouthSplitter => match prev_dir {
+                    East | West => {
+                        next_dir = North;
+                        next_dir2 = Some(South);
+                    }
+                    _ => (),
+                },
+                Tile::EastWestSplitter => match prev_dir {
+                    North | South => {
+                        next_dir = East;
+                        next_dir2 = Some(West);
+                    }
+                    _ => (),
+                },
+                Tile::SlashMirror => {
+                    next_dir = match prev_dir {
+                        North => East,
+                        East => North,
+                        South => West,
+                        West => South,
+                    };
+                }
+                Tile::BackSlashMirror => {
+                    next_dir = match prev_dir {
+                        North => West,
+                        East => South,
+                        South => East,
+                        West => North,
+                    };
+                }
+                Tile::Empty => (),
+            }
+        }
+
+        let maybe_succ1 = puzzle_input
+            .try_move((row, column), next_dir)
+            .map(|(next_row, next_col)| State::new(next_row, next_col, next_dir));
+
+        let maybe_succ2 = next_dir2.and_then(|dir| {
+            puzzle_input
+                .try_move((row, column), dir)
+                .map(|(next_row, next_col)| State::new(next_row, next_col, dir))
+        });
+
+        if let Some(succ1) = maybe_succ1 {
+            if let Some(succ2) = maybe_succ2 {
+                GetSuccessorsResult::Two(succ1, succ2)
+            } else {
+                GetSuccessorsResult::One(succ1)
+            }
+        } else if let Some(succ2) = maybe_succ2 {
+            GetSuccessorsResult::One(succ2)
+        } else {
+            GetSuccessorsResult::Zero
+        }
+    }
+}
+
+// the beams moving a tile at a time, a beam stops once it's somewhere a beam has already been
+struct Beams {
+    heads: Vec<State>,
+    explored: HashSet<State>,
+}
+
+impl Beams {
+    fn new(initial_state: State) -> Self {
+        Beams {
+            heads: vec![initial_state],
+            explored: HashSet::from([initial_state]),
+        }
+    }
+
+    // moves every beam one tile, false once there are no beams left
+    fn step(&mut self, puzzle_input: &Grid<Tile>) -> bool {
+        let mut next_heads = Vec::new();
+
+        for head in &self.heads {
+            match head.get_successors(puzzle_input) {
+                GetSuccessorsResult::One(succ) => next_heads.push(succ),
+                GetSuccessorsResult::Two(succ1, succ2) => {
+                    next_heads.push(succ1);
+                    next_heads.push(succ2);
+                }
+                GetSuccessorsResult::Zero => (),
+            }
+        }
+
+        next_heads.retain(|&head| self.explored.insert(head));
+        self.heads = next_heads;
+
+        !self.heads.is_empty()
+    }
+
+    // every tile a beam has passed through so far
+    fn energized(&self) -> HashSet<Position> {
+        self.explored
+            .iter()
+            .map(|state| (state.row, state.column))
+            .collect()
+    }
+}
+
+// every tile a beam starting from initial_state passes through
+fn energized(puzzle_input: &Grid<Tile>, initial_state: State) -> HashSet<Position> {
+    let mut beams = Beams::new(initial_state);
+    while beams.step(puzzle_input) {}
+
+    beams.energized()
+}
+
+fn num_energized(puzzle_input: &Grid<Tile>, initial_state: State) -> usize {
+    energized(puzzle_input, initial_state).len()
+}
+
+// a beam coming in from every tile on the edge, heading away from that edge
+fn edge_states(puzzle_input: &Grid<Tile>) -> impl Iterator<Item = State> + '_ {
+    let from_left = (0..puzzle_input.num_rows()).map(|row| State::new(row, 0, Direction::East));
+    let from_right = (0..puzzle_input.num_rows())
+        .map(|row| State::new(row, puzzle_input.num_columns() - 1, Direction::West));
+    let from_top =
+        (0..puzzle_input.num_columns()).map(|column| State::new(0, column, Direction::South));
+    let from_bottom = (0..puzzle_input.num_columns())
+        .map(|column| State::new(puzzle_input.num_rows() - 1, column, Direction::North));
+
+    from_left
+        .chain(from_right)
+        .chain(from_top)
+        .chain(from_bottom)
+}
+
+pub fn part1(puzzle_input: &Grid<Tile>) -> usize {
+    num_energized(puzzle_input, State::new(0, 0, Direction::East))
+}
+
+pub fn part2(puzzle_input: &Grid<Tile>) -> usize {
+    thread::scope(|s| {
+        let threads = edge_states(puzzle_input)
+            .map(|state| s.spawn(move || num_energized(puzzle_input, state)))
+            .collect::<Vec<_>>();
+
+        threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .max()
+            .unwrap()
+    })
+}
+
+// where the beam comes in, the top left like in part 1 or whichever edge energizes the most tiles
+// like in part 2
+fn initial_state(puzzle_input: &Grid<Tile>, from_best_edge: bool) -> State {
+    if from_best_edge {
+        edge_states(puzzle_input)
+            .max_by_key(|&state| num_energized(puzzle_input, state))
+            .unwrap()
+    } else {
+        State::new(0, 0, Direction::East)
+    }
+}
+
+// energized tiles in yellow, the mirrors and splitters in grey and the heads of the beams in white
+fn draw(puzzle_input: &Grid<Tile>, energized: &HashSet<Position>, heads: &[State]) -> Image {
+    let mut image = puzzle_input.map_with_position(|position, tile| {
+        match (energized.contains(&position), tile) {
+            (true, Tile::Empty) => [250, 210, 50],
+            (true, _) => [250, 240, 170],
+            (false, Tile::Empty) => image::BLACK,
+            (false, _) => [90, 90, 90],
+        }
+    });
+
+    for head in heads {
+        image[(head.row, head.column)] = image::WHITE;
+    }
+
+    image
+}
+
+// every tile the beam energizes once it has stopped
+pub fn render(puzzle_input: &Grid<Tile>, from_best_edge: bool) -> Image {
+    let initial_state = initial_state(puzzle_input, from_best_edge);
+    draw(puzzle_input, &energized(puzzle_input, initial_state), &[])
+}
+
+// the beams spreading out a tile per frame, until they energize everything render shows
+pub fn frames(puzzle_input: Grid<Tile>, from_best_edge: bool) -> impl Iterator<Item = Image> {
+    let mut beams = Beams::new(initial_state(&puzzle_input, from_best_edge));
+    let mut done = false;
+
+    iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        // the last frame is after the last beam has stopped
+        let frame = draw(&puzzle_input, &beams.energized(), &beams.heads);
+        done = beams.heads.is_empty();
+        beams.step(&puzzle_input);
+        Some(frame)
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = Grid<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(puzzle_input: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part1(puzzle_input))
+    }
+
+    fn part2(puzzle_input: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part2(puzzle_input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&parse_input(TEST_INPUT)?), 46);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&parse_input(TEST_INPUT)?), 51);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_frames() -> Result<()> {
+        let puzzle_input = parse_input(TEST_INPUT)?;
+        let frames = frames(puzzle_input.clone(), false).collect::<Vec<_>>();
+
+        // the beam starts on the top left tile
+        assert_eq!(frames[0][(0, 0)], image::WHITE);
+        assert_eq!(frames.last(), Some(&render(&puzzle_input, false)));
+
+        Ok(())
+    }
+}
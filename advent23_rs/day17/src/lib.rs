@@ -0,0 +1,235 @@
+use anyhow::{Context, Result};
+use common::{ParseError, Solution};
+use grid::{Direction, Grid};
+use image::Image;
+
+mod search;
+
+use search::{a_star_search, a_star_search_path};
+
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Ok(Grid::parse_with(input, |c| {
+        (c as char).to_digit(10).map(|digit| digit as u8)
+    })?)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Position {
+    row: usize,
+    column: usize,
+}
+
+impl Position {
+    const ZERO: Position = Position { row: 0, column: 0 };
+
+    fn new(row: usize, column: usize) -> Self {
+        Position { row, column }
+    }
+
+    fn manhattan_distance(&self, other: &Self) -> usize {
+        let di = self.row.abs_diff(other.row);
+
+        let dj = self.column.abs_diff(other.column);
+
+        di + dj
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    position: Position,
+    direction: Direction,
+
+    // how many times you have gone straight in a row
+    num_straight: usize,
+}
+
+impl State {
+    fn get_successors(
+        &self,
+        puzzle_input: &Grid<u8>,
+        is_ultra_crucible: bool,
+    ) -> Vec<(usize, State)> {
+        let mut result = Vec::new();
+
+        let mut try_going_dir = |dir| {
+            if let Some((row, column)) =
+                puzzle_input.try_move((self.position.row, self.position.column), dir)
+            {
+                let position = Position::new(row, column);
+                result.push((
+                    puzzle_input[(row, column)] as usize,
+                    State {
+                        position,
+                        direction: dir,
+                        num_straight: if dir == self.direction {
+                            self.num_straight + 1
+                        } else {
+                            1
+                        },
+                    },
+                ))
+            }
+        };
+
+        let can_go_straight = if is_ultra_crucible {
+            self.num_straight < 10
+        } else {
+            self.num_straight < 3
+        };
+
+        let can_turn = if is_ultra_crucible {
+            self.num_straight >= 4
+        } else {
+            true
+        };
+
+        if can_go_straight {
+            // try going straight
+            try_going_dir(self.direction);
+        }
+
+        if can_turn {
+            try_going_dir(self.direction.turn_right());
+            try_going_dir(self.direction.turn_left());
+        }
+
+        result
+    }
+}
+
+// an ultra crucible can find no path at all, when the goal can't be reached going straight for 4
+fn get_minimum_heat(puzzle_input: &Grid<u8>, is_ultra_crucible: bool) -> Result<usize> {
+    let initial_state = State {
+        position: Position::ZERO,
+        direction: Direction::East,
+        num_straight: 0,
+    };
+
+    let goal_position = Position::new(puzzle_input.num_rows() - 1, puzzle_input.num_columns() - 1);
+
+    a_star_search(
+        initial_state,
+        |state| state.get_successors(puzzle_input, is_ultra_crucible),
+        // ultra crucible must go straight 4 times before goal
+        |state| state.position == goal_position && (!is_ultra_crucible || state.num_straight >= 4),
+        |state| state.position.manhattan_distance(&goal_position),
+    )
+    .context("no path to the goal")
+}
+
+// the states along a path with the minimum heat loss
+fn get_best_path(puzzle_input: &Grid<u8>, is_ultra_crucible: bool) -> Result<Vec<State>> {
+    let initial_state = State {
+        position: Position::ZERO,
+        direction: Direction::East,
+        num_straight: 0,
+    };
+
+    let goal_position = Position::new(puzzle_input.num_rows() - 1, puzzle_input.num_columns() - 1);
+
+    a_star_search_path(
+        initial_state,
+        |state| state.get_successors(puzzle_input, is_ultra_crucible),
+        |state| state.position == goal_position && (!is_ultra_crucible || state.num_straight >= 4),
+        |state| state.position.manhattan_distance(&goal_position),
+    )
+    .map(|(_, path)| path)
+    .context("no path to the goal")
+}
+
+// the heat loss of each block from blue (1) to red (9), with the crucible's path in white
+pub fn render(puzzle_input: &Grid<u8>, is_ultra_crucible: bool) -> Result<Image> {
+    let mut image = puzzle_input
+        .map(|&heat_loss| image::lerp([20, 30, 90], [230, 70, 30], (heat_loss as f64 - 1.0) / 8.0));
+
+    for state in get_best_path(puzzle_input, is_ultra_crucible)? {
+        image[(state.position.row, state.position.column)] = image::WHITE;
+    }
+
+    Ok(image)
+}
+
+pub fn part1(puzzle_input: &Grid<u8>) -> Result<usize> {
+    get_minimum_heat(puzzle_input, false)
+}
+
+pub fn part2(puzzle_input: &Grid<u8>) -> Result<usize> {
+    get_minimum_heat(puzzle_input, true)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(puzzle_input: &Self::Parsed<'_>) -> Result<usize> {
+        part1(puzzle_input)
+    }
+
+    fn part2(puzzle_input: &Self::Parsed<'_>) -> Result<usize> {
+        part2(puzzle_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const TEST_INPUT_A: &str = "2413432311323\n\
+                                3215453535623\n\
+                                3255245654254\n\
+                                3446585845452\n\
+                                4546657867536\n\
+                                1438598798454\n\
+                                4457876987766\n\
+                                3637877979653\n\
+                                4654967986887\n\
+                                4564679986453\n\
+                                1224686865563\n\
+                                2546548887735\n\
+                                4322674655533";
+
+    const TEST_INPUT_B: &str = "111111111111\n\
+                                999999999991\n\
+                                999999999991\n\
+                                999999999991\n\
+                                999999999991";
+
+    #[test]
+    fn test_part_1() {
+        let puzzle_input = parse_input(TEST_INPUT_A).unwrap();
+
+        assert_eq!(part1(&puzzle_input).unwrap(), 102);
+    }
+
+    #[test]
+    fn test_part_2a() {
+        let puzzle_input = parse_input(TEST_INPUT_A).unwrap();
+
+        assert_eq!(part2(&puzzle_input).unwrap(), 94);
+    }
+
+    #[test]
+    fn test_part_2b() {
+        let puzzle_input = parse_input(TEST_INPUT_B).unwrap();
+
+        assert_eq!(part2(&puzzle_input).unwrap(), 71);
+    }
+
+    #[test]
+    fn test_no_path() {
+        let puzzle_input = parse_input("1\n").unwrap();
+
+        assert_eq!(part1(&puzzle_input).unwrap(), 0);
+        let err = part2(&puzzle_input).unwrap_err();
+        assert_eq!(err.to_string(), "no path to the goal");
+    }
+}
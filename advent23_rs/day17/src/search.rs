@@ -57,14 +57,12 @@ where
     None
 }
 
-#[allow(dead_code)]
 struct StateInfo<T: Clone + PartialEq + Eq + Hash, C: PrimInt> {
     cost: C,
     parent: Option<T>,
 }
 
 // get the cost and the path taken
-#[allow(dead_code)]
 pub fn a_star_search_path<T, C>(
     initial_state: T,
 
@@ -0,0 +1,215 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Serialize)]
+enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, ParseError> {
+        use Direction::*;
+        match value {
+            "R" => Ok(Right),
+            "D" => Ok(Down),
+            "L" => Ok(Left),
+            "U" => Ok(Up),
+            _ => Err(ParseError::new(value, "expected a direction: R, D, L or U")),
+        }
+    }
+}
+
+impl TryFrom<u32> for Direction {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, u32> {
+        use Direction::*;
+        match value {
+            0 => Ok(Right),
+            1 => Ok(Down),
+            2 => Ok(Left),
+            3 => Ok(Up),
+            _ => Err(value),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Instruction {
+    direction: Direction,
+    length: usize,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, ParseError> {
+        let mut iter = line.split_ascii_whitespace();
+        let mut next_field = || {
+            iter.next().ok_or_else(|| {
+                ParseError::missing_after(line, "expected a line like \"R 6 (#70c710)\"")
+            })
+        };
+
+        let direction = next_field()?.try_into()?;
+
+        let length = parse_number(next_field()?)?;
+
+        Ok(Instruction { direction, length })
+    }
+}
+
+impl Instruction {
+    fn try_from_part2(line: &str) -> Result<Self, ParseError> {
+        // ex:
+        // line = "R 6 (#70c710)""
+        // instr = "70c710" => parse into Instruction { direction: Direction::Right, length: 461937 }
+        let instr = line
+            .rsplit_once('#')
+            .and_then(|(_, color)| color.rsplit_once(')'))
+            .map(|(instr, _)| instr)
+            .filter(|instr| instr.len() == 6 && instr.is_ascii())
+            .ok_or_else(|| ParseError::new(line, "expected a color like \"(#70c710)\""))?;
+
+        // the last digit is the direction, the rest is the length
+        let (length_hex, direction_digit) = instr.split_at(5);
+
+        let direction = u32::from_str_radix(direction_digit, 4)
+            .ok()
+            .and_then(|digit| Direction::try_from(digit).ok())
+            .ok_or_else(|| ParseError::new(direction_digit, "expected a direction digit 0-3"))?;
+        let length = usize::from_str_radix(length_hex, 16)
+            .map_err(|_| ParseError::new(length_hex, "expected a hexadecimal length"))?;
+
+        Ok(Instruction { direction, length })
+    }
+}
+
+pub fn parse_input_part1(file_contents: &str) -> Result<Vec<Instruction>, ParseError> {
+    file_contents.lines().map(Instruction::try_from).collect()
+}
+
+pub fn parse_input_part2(file_contents: &str) -> Result<Vec<Instruction>, ParseError> {
+    file_contents
+        .lines()
+        .map(Instruction::try_from_part2)
+        .collect()
+}
+
+#[derive(Debug, Default, Clone)]
+struct Position {
+    x: isize,
+    y: isize,
+}
+
+impl Position {
+    fn new(x: isize, y: isize) -> Self {
+        Position { x, y }
+    }
+
+    fn move_in_direction(&self, direction: Direction, length: usize) -> Self {
+        match direction {
+            Direction::Right => Position::new(self.x + length as isize, self.y),
+            Direction::Down => Position::new(self.x, self.y + length as isize),
+            Direction::Left => Position::new(self.x - length as isize, self.y),
+            Direction::Up => Position::new(self.x, self.y - length as isize),
+        }
+    }
+}
+
+pub fn solve(puzzle_input: &[Instruction]) -> usize {
+    // shoelace formula:
+    let (double_area, border, _) = puzzle_input.iter().fold(
+        (0isize, 0, Position::default()),
+        |(double_area, border, vertex), &Instruction { direction, length }| {
+            let next_vertex = vertex.move_in_direction(direction, length);
+
+            let double_area = double_area + (vertex.x * next_vertex.y - next_vertex.x * vertex.y);
+            let border = border + length;
+
+            (double_area, border, next_vertex)
+        },
+    );
+
+    // pick's theorem:
+    // i = inside squares, b = border squares
+    // A = i + b/2 - 1
+    // therefore i + b = A + b/2 + 1
+    // since we want i + b (number of squares that are #)
+    // then we need A + b/2 + 1
+    // the way we calculated area above either finds positive or negative area so must abs
+    (double_area.unsigned_abs() + border) / 2 + 1
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    // the dig plan as read in part 1, and as decoded from the colors in part 2
+    type Parsed<'a> = (Vec<Instruction>, Vec<Instruction>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((parse_input_part1(input)?, parse_input_part2(input)?))
+    }
+
+    fn part1((instructions, _): &Self::Parsed<'_>) -> Result<usize> {
+        Ok(solve(instructions))
+    }
+
+    fn part2((_, instructions): &Self::Parsed<'_>) -> Result<usize> {
+        Ok(solve(instructions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const TEST_INPUT: &str = "R 6 (#70c710)\n\
+                              D 5 (#0dc571)\n\
+                              L 2 (#5713f0)\n\
+                              D 2 (#d2c081)\n\
+                              R 2 (#59c680)\n\
+                              D 2 (#411b91)\n\
+                              L 5 (#8ceee2)\n\
+                              U 2 (#caa173)\n\
+                              L 1 (#1b58a2)\n\
+                              U 2 (#caa171)\n\
+                              R 2 (#7807d2)\n\
+                              U 3 (#a77fa3)\n\
+                              L 2 (#015232)\n\
+                              U 2 (#7a21e3)";
+
+    #[test]
+    fn test_part1() {
+        let puzzle_input = parse_input_part1(TEST_INPUT).unwrap();
+
+        assert_eq!(solve(&puzzle_input), 62);
+    }
+
+    #[test]
+    fn test_part2() {
+        let puzzle_input = parse_input_part2(TEST_INPUT).unwrap();
+
+        assert_eq!(solve(&puzzle_input), 952408144115);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "R 6 (#70c710)\nD 5 (#0dc574)";
+
+        let err = parse_input_part2(input).unwrap_err().locate(input);
+
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 12);
+        assert_eq!(err.text, "4");
+    }
+}
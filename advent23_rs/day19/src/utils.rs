@@ -1,9 +1,11 @@
-use anyhow::{Context, Result};
+use common::{parse_number, ParseError};
+use interval::HyperRect;
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::{collections::HashMap, ops::Range};
+use serde::Serialize;
+use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub enum Category {
     X,
     M,
@@ -11,19 +13,21 @@ pub enum Category {
     S,
 }
 
-impl From<&str> for Category {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for Category {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, ParseError> {
         match value {
-            "x" => Category::X,
-            "m" => Category::M,
-            "a" => Category::A,
-            "s" => Category::S,
-            _ => panic!("Cannot convert \"{}\" into Category", value),
+            "x" => Ok(Category::X),
+            "m" => Ok(Category::M),
+            "a" => Ok(Category::A),
+            "s" => Ok(Category::S),
+            _ => Err(ParseError::new(value, "expected a category: x, m, a or s")),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Part {
     pub x: u64,
     pub m: u64,
@@ -55,34 +59,40 @@ impl Part {
 }
 
 impl TryFrom<&str> for Part {
-    type Error = anyhow::Error;
+    type Error = ParseError;
 
-    fn try_from(line: &str) -> Result<Self> {
+    fn try_from(line: &str) -> Result<Self, ParseError> {
         lazy_static! {
             static ref PART_REGEX: Regex =
-                Regex::new(r"\{x=([0-9]+),m=([0-9]+),a=([0-9]+),s=([0-9]+)\}").unwrap();
+                Regex::new(r"^\{x=([0-9]+),m=([0-9]+),a=([0-9]+),s=([0-9]+)\}$").unwrap();
         }
 
-        let caps = PART_REGEX
-            .captures(line)
-            .with_context(|| format!("Unable to parse \"{}\" into Part", line))?;
-
-        let (_, value_strs) = caps.extract();
+        let caps = PART_REGEX.captures(line).ok_or_else(|| {
+            ParseError::new(
+                line,
+                "expected a part like \"{x=787,m=2655,a=1222,s=2876}\"",
+            )
+        })?;
 
-        let [x, m, a, s] = value_strs.map(|val_str| val_str.parse().unwrap());
+        let (_, [x, m, a, s]) = caps.extract();
 
-        Ok(Part { x, m, a, s })
+        Ok(Part {
+            x: parse_number(x)?,
+            m: parse_number(m)?,
+            a: parse_number(a)?,
+            s: parse_number(s)?,
+        })
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum Condition {
     LessThan(Category, u64),
     GreaterThan(Category, u64),
     Always,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum WorkflowResult {
     Workflow(Box<str>),
     Accept,
@@ -99,28 +109,28 @@ impl From<&str> for WorkflowResult {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Rule {
     pub condition: Condition,
     pub result: WorkflowResult,
 }
 
 impl TryFrom<&str> for Rule {
-    type Error = anyhow::Error;
+    type Error = ParseError;
 
-    fn try_from(value: &str) -> Result<Self> {
+    fn try_from(value: &str) -> Result<Self, ParseError> {
         lazy_static! {
             static ref RULE_REGEX: Regex =
-                Regex::new(r"(?<condition>(?<category>[xmas])(?<type>[<>])(?<value>[0-9]+):)?(?<result>[a-z]+|A|R)").unwrap();
+                Regex::new(r"^(?<condition>(?<category>[xmas])(?<type>[<>])(?<value>[0-9]+):)?(?<result>[a-z]+|A|R)$").unwrap();
         }
 
-        let caps = RULE_REGEX
-            .captures(value)
-            .with_context(|| format!("Invalid Rule string: \"{}\"", value))?;
+        let caps = RULE_REGEX.captures(value).ok_or_else(|| {
+            ParseError::new(value, "expected a rule like \"a<2006:qkq\" or \"rfg\"")
+        })?;
 
         let condition = if caps.name("condition").is_some() {
-            let category = caps["category"].into();
-            let value = caps["value"].parse().unwrap();
+            let category = caps.name("category").unwrap().as_str().try_into()?;
+            let value = parse_number(caps.name("value").unwrap().as_str())?;
 
             if &caps["type"] == "<" {
                 Condition::LessThan(category, value)
@@ -137,7 +147,7 @@ impl TryFrom<&str> for Rule {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Workflow {
     pub name: Box<str>,
     pub rules: Box<[Rule]>,
@@ -159,24 +169,36 @@ impl Workflow {
 }
 
 impl TryFrom<&str> for Workflow {
-    type Error = anyhow::Error;
+    type Error = ParseError;
 
-    fn try_from(line: &str) -> Result<Self> {
-        let (name, rest) = line.split_once('{').with_context(|| {
-            format!(
-                "When parsing \"{}\" as a Workflow, does not contain a {{",
-                line
-            )
+    fn try_from(line: &str) -> Result<Self, ParseError> {
+        let (name, rest) = line.split_once('{').ok_or_else(|| {
+            ParseError::new(line, "expected a workflow like \"px{a<2006:qkq,rfg}\"")
         })?;
 
-        let rest = rest.strip_suffix('}').with_context(|| {
-            format!(
-                "When parsing \"{}\" as a Workflow, does not end in }}",
-                line
-            )
+        let rest = rest.strip_suffix('}').ok_or_else(|| {
+            ParseError::missing_after(line, "expected the workflow to end in '}'")
         })?;
 
-        let rules = rest.split(',').map(Rule::try_from).collect::<Result<_>>()?;
+        let rules = rest
+            .split(',')
+            .map(Rule::try_from)
+            .collect::<Result<Box<[_]>, _>>()?;
+
+        // process_part relies on the last rule always applying
+        let last_rule = rest.rsplit(',').next().unwrap_or_default();
+        if !matches!(
+            rules.last(),
+            Some(Rule {
+                condition: Condition::Always,
+                ..
+            })
+        ) {
+            return Err(ParseError::new(
+                last_rule,
+                "expected the last rule to have no condition",
+            ));
+        }
 
         Ok(Workflow {
             name: name.into(),
@@ -205,86 +227,23 @@ impl WorflowMapExt for WorkflowMap {
     }
 }
 
-#[derive(Clone)]
-pub struct PartRanges {
-    pub x: Range<u64>,
-    pub m: Range<u64>,
-    pub a: Range<u64>,
-    pub s: Range<u64>,
-}
+// the ranges of ratings of each category, indexed by category
+pub type PartRanges = HyperRect<u64, 4>;
 
-pub enum PartRangesSplitResult {
-    All(PartRanges),
-    Some {
-        passes: PartRanges,
-        fails: PartRanges,
-    },
-    None(PartRanges),
+pub trait PartRangesExt: Sized {
+    fn split_on_condition(self, condition: &Condition) -> (Option<Self>, Option<Self>);
 }
 
-impl PartRanges {
-    pub fn get_range(&self, category: Category) -> &Range<u64> {
-        match category {
-            Category::X => &self.x,
-            Category::M => &self.m,
-            Category::A => &self.a,
-            Category::S => &self.s,
-        }
-    }
-
-    pub fn get_range_mut(&mut self, category: Category) -> &mut Range<u64> {
-        match category {
-            Category::X => &mut self.x,
-            Category::M => &mut self.m,
-            Category::A => &mut self.a,
-            Category::S => &mut self.s,
-        }
-    }
-
-    pub fn num_parts_possible(&self) -> u64 {
-        [&self.x, &self.m, &self.a, &self.s]
-            .into_iter()
-            .map(|range| range.end - range.start)
-            .product()
-    }
-
-    pub fn split_on_condition(self, condition: &Condition) -> PartRangesSplitResult {
+impl PartRangesExt for PartRanges {
+    // (the parts that pass condition, the parts that fail it)
+    fn split_on_condition(self, condition: &Condition) -> (Option<Self>, Option<Self>) {
         match *condition {
-            Condition::LessThan(category, val) => {
-                let range = self.get_range(category);
-
-                if range.end <= val {
-                    PartRangesSplitResult::All(self)
-                } else if range.start >= val {
-                    PartRangesSplitResult::None(self)
-                } else {
-                    let mut passes = self.clone();
-                    passes.get_range_mut(category).end = val;
-
-                    let mut fails = self;
-                    fails.get_range_mut(category).start = val;
-
-                    PartRangesSplitResult::Some { passes, fails }
-                }
-            }
+            Condition::LessThan(category, val) => self.split(category as usize, val),
             Condition::GreaterThan(category, val) => {
-                let range = self.get_range(category);
-
-                if range.start > val {
-                    PartRangesSplitResult::All(self)
-                } else if range.end <= val + 1 {
-                    PartRangesSplitResult::None(self)
-                } else {
-                    let mut passes = self.clone();
-                    passes.get_range_mut(category).start = val + 1;
-
-                    let mut fails = self;
-                    fails.get_range_mut(category).end = val + 1;
-
-                    PartRangesSplitResult::Some { passes, fails }
-                }
+                let (fails, passes) = self.split(category as usize, val + 1);
+                (passes, fails)
             }
-            Condition::Always => PartRangesSplitResult::All(self),
+            Condition::Always => (Some(self), None),
         }
     }
 }
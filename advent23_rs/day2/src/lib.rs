@@ -0,0 +1,508 @@
+use anyhow::{Context, Result};
+use common::{parse_number, ParseError, Solution};
+use serde::{Serialize, Serializer};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fmt,
+    ops::RangeInclusive,
+};
+
+mod likelihood;
+mod stream;
+
+pub use likelihood::{infer_bag, log_likelihood, smallest_bag, Inference};
+pub use stream::{Games, SumPossible, SumPowers};
+
+// the bag part 1 asks about
+const PART1_BAG: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+// how many cubes of each color were shown, in the order the reveal lists them. colors that
+// weren't shown are left out
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Reveal {
+    #[serde(serialize_with = "serialize_counts")]
+    counts: Vec<(Box<str>, u32)>,
+}
+
+// as a map from color to count
+fn serialize_counts<S: Serializer>(
+    counts: &[(Box<str>, u32)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(counts.iter().map(|(color, count)| (color, count)))
+}
+
+impl Reveal {
+    pub fn count(&self, color: &str) -> u32 {
+        self.counts
+            .iter()
+            .find(|(shown, _)| **shown == *color)
+            .map_or(0, |&(_, count)| count)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.counts.iter().map(|(color, _)| &**color)
+    }
+
+    // each color with its count, in the order the reveal lists them
+    pub fn counts(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts.iter().map(|(color, count)| (&**color, *count))
+    }
+}
+
+impl TryFrom<&str> for Reveal {
+    type Error = ParseError;
+
+    fn try_from(reveal_str: &str) -> Result<Self, ParseError> {
+        let mut counts = Vec::<(Box<str>, u32)>::new();
+        for num_and_color in reveal_str.split(',') {
+            let mut iter = num_and_color.split(' ').filter(|s| !s.is_empty());
+
+            let num = parse_number(iter.next().ok_or_else(|| {
+                ParseError::new(num_and_color, "expected a number of cubes and a color")
+            })?)?;
+
+            let color = iter
+                .next()
+                .ok_or_else(|| ParseError::missing_after(num_and_color, "expected a color"))?;
+
+            if counts.iter().any(|(shown, _)| **shown == *color) {
+                return Err(ParseError::new(
+                    color,
+                    "expected each color once per reveal",
+                ));
+            }
+            counts.push((color.into(), num));
+        }
+
+        Ok(Reveal { counts })
+    }
+}
+
+// how many cubes of each color are in the bag, written like a reveal: "12 red, 13 green"
+#[derive(Debug, Clone, Default)]
+pub struct Bag {
+    counts: BTreeMap<Box<str>, u32>,
+}
+
+impl Bag {
+    pub fn part1() -> Self {
+        Bag {
+            counts: PART1_BAG
+                .iter()
+                .map(|&(color, count)| (color.into(), count))
+                .collect(),
+        }
+    }
+
+    pub fn count(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(|color| &**color)
+    }
+
+    // a color in the palette this doesn't have counts as none of it. None if enough colors make
+    // the power too big for a u64
+    fn power<'a>(&self, palette: impl IntoIterator<Item = &'a str>) -> Option<u64> {
+        let counts = palette
+            .into_iter()
+            .map(|color| u64::from(self.count(color)))
+            .collect::<Vec<_>>();
+        if counts.contains(&0) {
+            return Some(0);
+        }
+        counts.into_iter().try_fold(1, u64::checked_mul)
+    }
+}
+
+// like a reveal, in alphabetical order of color
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (color, count)) in self.counts.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{count} {color}")?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for Bag {
+    type Error = ParseError;
+
+    fn try_from(bag_str: &str) -> Result<Self, ParseError> {
+        let reveal = Reveal::try_from(bag_str)?;
+        Ok(Bag {
+            counts: reveal.counts.into_iter().collect(),
+        })
+    }
+}
+
+// the reveal that shows a game couldn't have been played with a bag, and the color there are too
+// many of in it
+#[derive(Debug, PartialEq, Eq)]
+pub struct Impossible<'a> {
+    // counting from 1
+    pub reveal: usize,
+    pub color: &'a str,
+    pub shown: u32,
+    pub in_bag: u32,
+}
+
+impl fmt::Display for Impossible<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "reveal {} shows {} {} but the bag only has {}",
+            self.reveal, self.shown, self.color, self.in_bag
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Game {
+    // from the "Game N:" the line starts with
+    id: i32,
+    reveals: Vec<Reveal>,
+}
+
+impl Game {
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn reveals(&self) -> &[Reveal] {
+        &self.reveals
+    }
+
+    pub fn possible_with(&self, bag: &Bag) -> bool {
+        self.first_impossible(bag).is_none()
+    }
+
+    // the first reveal with more of a color than the bag has, and the first such color in the
+    // order the reveal lists them
+    pub fn first_impossible(&self, bag: &Bag) -> Option<Impossible<'_>> {
+        self.reveals.iter().enumerate().find_map(|(i, reveal)| {
+            reveal.counts().find_map(|(color, shown)| {
+                let in_bag = bag.count(color);
+                (shown > in_bag).then_some(Impossible {
+                    reveal: i + 1,
+                    color,
+                    shown,
+                    in_bag,
+                })
+            })
+        })
+    }
+
+    // the most cubes of each color any reveal showed
+    fn get_min_possible_counts(&self) -> Bag {
+        smallest_bag(&self.reveals)
+    }
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, ParseError> {
+        let (header, reveals_str) = line.split_once(':').ok_or_else(|| {
+            ParseError::new(line, "expected a line like \"Game 1: 3 blue, 4 red\"")
+        })?;
+
+        let id_str = header
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::new(header, "expected a header like \"Game 1\""))?;
+
+        let id_str = id_str.trim();
+        let id = parse_number(id_str)?;
+        if id < 1 {
+            return Err(ParseError::new(id_str, "expected a game id of at least 1"));
+        }
+
+        Ok(Game {
+            id,
+            reveals: reveals_str
+                .split(';')
+                .map(Reveal::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+// every color that shows up in any of the games
+pub fn palette(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| &game.reveals)
+        .flat_map(Reveal::colors)
+        .collect()
+}
+
+// a game whose id is already in ids is an error pointing at its header, otherwise its id is added
+fn parse_game(line: &str, ids: Option<&mut HashSet<i32>>) -> Result<Game, ParseError> {
+    let game = Game::try_from(line)?;
+    if let Some(ids) = ids {
+        if !ids.insert(game.id) {
+            let header = line.split_once(':').unwrap().0;
+            return Err(ParseError::new(
+                header,
+                format!("expected each game id once, game {} is repeated", game.id),
+            ));
+        }
+    }
+    Ok(game)
+}
+
+// the games can be in any order, but each id can only be used once
+pub fn parse_puzzle_input(puzzle_input: &str) -> Result<Vec<Game>, ParseError> {
+    let mut ids = HashSet::new();
+
+    puzzle_input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_game(line, Some(&mut ids)))
+        .collect()
+}
+
+// the runs of ids up to the highest one that no game has, for inputs that have had games taken out
+pub fn missing_ids(games: &[Game]) -> Vec<RangeInclusive<i32>> {
+    let mut ids = games.iter().map(Game::id).collect::<Vec<_>>();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let mut missing = Vec::new();
+    let mut next = 1;
+    for id in ids {
+        if id > next {
+            missing.push(next..=id - 1);
+        }
+        next = id.saturating_add(1);
+    }
+
+    missing
+}
+
+// the id of every game that couldn't have been played with the bag, with why not
+pub fn impossible_games<'a>(
+    games: &'a [Game],
+    bag: &'a Bag,
+) -> impl Iterator<Item = (i32, Impossible<'a>)> + 'a {
+    games
+        .iter()
+        .filter_map(|game| Some((game.id, game.first_impossible(bag)?)))
+}
+
+// the ids of the games that could have been played with the bag, added up in i64 like
+// SumPossible does
+pub fn sum_possible(games: &[Game], bag: &Bag) -> i64 {
+    games
+        .iter()
+        .filter(|game| game.possible_with(bag))
+        .map(|game| i64::from(game.id))
+        .sum()
+}
+
+pub fn part1(games: &[Game]) -> i64 {
+    sum_possible(games, &Bag::part1())
+}
+
+// a game that never shows one of the colors the others do needs none of it, so its power is 0
+pub fn part2(games: &[Game]) -> Result<u64> {
+    let palette = palette(games);
+    games.iter().try_fold(0u64, |sum, game| {
+        game.get_min_possible_counts()
+            .power(palette.iter().copied())
+            .and_then(|power| sum.checked_add(power))
+            .with_context(|| format!("the powers overflow a u64 at game {}", game.id))
+    })
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<Game>;
+    type Answer1 = i64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_puzzle_input(input)
+    }
+
+    fn part1(games: &Self::Parsed<'_>) -> Result<i64> {
+        Ok(part1(games))
+    }
+
+    fn part2(games: &Self::Parsed<'_>) -> Result<u64> {
+        part2(games)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+                              Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+                              Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+                              Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+                              Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_puzzle_input(TEST_INPUT).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(&parse_puzzle_input(TEST_INPUT).unwrap()).unwrap(),
+            2286
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, two green";
+
+        let err = parse_puzzle_input(input).unwrap_err().locate(input);
+
+        assert_eq!(err, ParseError::at(2, 17, "two", "expected a number"));
+    }
+
+    #[test]
+    fn test_other_colors() {
+        let input = "Game 1: 2 yellow, 1 red; 3 purple\n\
+                     Game 2: 1 red, 1 yellow, 4 purple; 2 yellow\n\
+                     Game 3: 5 red, 2 purple";
+        let games = parse_puzzle_input(input).unwrap();
+
+        assert_eq!(palette(&games), BTreeSet::from(["purple", "red", "yellow"]));
+        assert_eq!(games[1].get_min_possible_counts().count("yellow"), 2);
+        // 1 red, 2 yellow and 3 purple, then 1, 2 and 4, and game 3 has no yellow at all
+        assert_eq!(part2(&games).unwrap(), 6 + 8);
+        // part 1's bag has no yellow or purple cubes
+        assert_eq!(part1(&games), 0);
+
+        let input = "Game 1: 3 blue, 4 blue";
+        let err = parse_puzzle_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            err,
+            ParseError::at(1, 19, "blue", "expected each color once per reveal")
+        );
+
+        // there's no such thing as a negative number of cubes
+        let input = "Game 1: -3 red";
+        let err = parse_puzzle_input(input).unwrap_err().locate(input);
+        assert_eq!(err, ParseError::at(1, 9, "-3", "expected a number"));
+
+        // 20 of each of 8 colors is already more than a u32
+        let input = "Game 1: 20 a, 20 b, 20 c, 20 d, 20 e, 20 f, 20 g, 20 h";
+        assert_eq!(
+            part2(&parse_puzzle_input(input).unwrap()).unwrap(),
+            20u64.pow(8)
+        );
+        let input = "Game 1: 4000000000 red, 4000000000 green, 4000000000 blue";
+        let err = part2(&parse_puzzle_input(input).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "the powers overflow a u64 at game 1");
+    }
+
+    #[test]
+    fn test_impossible_games() {
+        let games = parse_puzzle_input(TEST_INPUT).unwrap();
+
+        let report = impossible_games(&games, &Bag::part1())
+            .map(|(id, impossible)| format!("{id}: {impossible}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            report,
+            [
+                "3: reveal 1 shows 20 red but the bag only has 12",
+                "4: reveal 3 shows 15 blue but the bag only has 14"
+            ]
+        );
+
+        // a bag without any green rules out every game
+        let bag = Bag::try_from("20 red, 20 blue").unwrap();
+        assert!(games.iter().all(|game| !game.possible_with(&bag)));
+        assert_eq!(
+            games[0].first_impossible(&bag),
+            Some(Impossible {
+                reveal: 2,
+                color: "green",
+                shown: 2,
+                in_bag: 0
+            })
+        );
+
+        let bag = Bag::try_from("6 red, 3 green, 6 blue").unwrap();
+        assert_eq!(sum_possible(&games, &bag), 1 + 2 + 5);
+
+        // red is too many first, even though blue comes first alphabetically
+        let games = parse_puzzle_input("Game 1: 2 green; 20 red, 15 blue").unwrap();
+        assert_eq!(
+            games[0].first_impossible(&Bag::part1()),
+            Some(Impossible {
+                reveal: 2,
+                color: "red",
+                shown: 20,
+                in_bag: 12
+            })
+        );
+    }
+
+    #[test]
+    fn test_game_ids() {
+        // game 2 has been taken out and the rest shuffled
+        let input = "Game 5: 6 red, 1 blue, 3 green\n\
+                     Game 1: 3 blue, 4 red\n\
+                     Game 4: 1 green, 3 red, 6 blue; 14 red, 15 blue\n\
+                     Game 3: 8 green, 6 blue, 20 red";
+        let games = parse_puzzle_input(input).unwrap();
+
+        assert_eq!(part1(&games), 5 + 1);
+        assert_eq!(missing_ids(&games), [2..=2]);
+        assert_eq!(
+            impossible_games(&games, &Bag::part1())
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>(),
+            [4, 3]
+        );
+
+        let input = "Game 1: 3 blue\nGame 2: 4 red\nGame 1: 1 green";
+        let err = parse_puzzle_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            err,
+            ParseError::at(
+                3,
+                1,
+                "Game 1",
+                "expected each game id once, game 1 is repeated"
+            )
+        );
+
+        // the gaps are found from the ids there are, however far apart they are
+        let games = parse_puzzle_input("Game 3: 1 red\nGame 2000000000: 1 red").unwrap();
+        assert_eq!(missing_ids(&games), [1..=2, 4..=1999999999]);
+
+        // the biggest ids there are still add up
+        let games = parse_puzzle_input("Game 1: 1 red\nGame 2147483647: 1 red").unwrap();
+        assert_eq!(part1(&games), 2147483648);
+
+        let input = "Game 0: 3 blue";
+        let err = parse_puzzle_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            err,
+            ParseError::at(1, 6, "0", "expected a game id of at least 1")
+        );
+
+        let input = "Round 1: 3 blue";
+        let err = parse_puzzle_input(input).unwrap_err().locate(input);
+        assert_eq!(
+            err,
+            ParseError::at(1, 1, "Round 1", "expected a header like \"Game 1\"")
+        );
+    }
+}
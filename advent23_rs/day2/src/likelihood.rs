@@ -0,0 +1,262 @@
+use crate::{Bag, Reveal};
+use std::{collections::BTreeMap, f64::consts::PI};
+
+// a change in log likelihood smaller than this is rounding, not an improvement
+const EPSILON: f64 = 1e-9;
+
+// ln(n!) for n below this comes from a table, and from Stirling's series past it, so a bag of
+// billions of cubes doesn't need a table that long
+const TABLE_LEN: usize = 1024;
+
+// ln(n!) for every n so far below TABLE_LEN, worked out as they are needed
+struct LnFactorials(Vec<f64>);
+
+impl LnFactorials {
+    fn new() -> Self {
+        LnFactorials(vec![0.0])
+    }
+
+    fn get(&mut self, n: usize) -> f64 {
+        if n >= TABLE_LEN {
+            return ln_factorial_stirling(n);
+        }
+
+        while self.0.len() <= n {
+            let next = self.0.len();
+            self.0.push(self.0[next - 1] + (next as f64).ln());
+        }
+        self.0[n]
+    }
+
+    // ln of n choose k. when n is past the table but k or n - k isn't, the factors of n! that
+    // don't cancel are added up directly, subtracting Stirling's series for two huge n! would
+    // lose the digits that matter
+    fn ln_choose(&mut self, n: usize, k: usize) -> f64 {
+        let k = k.min(n - k);
+        if n >= TABLE_LEN && k < TABLE_LEN {
+            return (n - k + 1..=n).map(|i| (i as f64).ln()).sum::<f64>() - self.get(k);
+        }
+        self.get(n) - self.get(k) - self.get(n - k)
+    }
+}
+
+// good to much better than EPSILON from TABLE_LEN up
+fn ln_factorial_stirling(n: usize) -> f64 {
+    let n = n as f64;
+    n * n.ln() - n + 0.5 * (2.0 * PI * n).ln() + 1.0 / (12.0 * n) - 1.0 / (360.0 * n.powi(3))
+}
+
+// each reveal is a handful of cubes drawn from the bag without replacement, and put back before
+// the next one. a reveal with more of a color than the bag has can't happen, so it's -inf
+fn log_likelihood_with(
+    counts: &BTreeMap<Box<str>, u32>,
+    reveals: &[&Reveal],
+    ln_factorials: &mut LnFactorials,
+) -> f64 {
+    let num_cubes = counts.values().map(|&count| count as usize).sum::<usize>();
+    let mut log_likelihood = 0.0;
+
+    for reveal in reveals {
+        let mut num_drawn = 0;
+        for (color, shown) in reveal.counts() {
+            let in_bag = counts.get(color).copied().unwrap_or(0);
+            if shown > in_bag {
+                return f64::NEG_INFINITY;
+            }
+            log_likelihood += ln_factorials.ln_choose(in_bag as usize, shown as usize);
+            num_drawn += shown as usize;
+        }
+        log_likelihood -= ln_factorials.ln_choose(num_cubes, num_drawn);
+    }
+
+    log_likelihood
+}
+
+// the natural log of how likely the reveals are to have been drawn from the bag
+pub fn log_likelihood<'a>(bag: &Bag, reveals: impl IntoIterator<Item = &'a Reveal>) -> f64 {
+    let reveals = reveals.into_iter().collect::<Vec<_>>();
+    log_likelihood_with(&bag.counts, &reveals, &mut LnFactorials::new())
+}
+
+// the fewest cubes the reveals could have been drawn from, the most of each color any of them shows
+pub fn smallest_bag<'a>(reveals: impl IntoIterator<Item = &'a Reveal>) -> Bag {
+    let mut counts = BTreeMap::<Box<str>, u32>::new();
+    for (color, shown) in reveals.into_iter().flat_map(Reveal::counts) {
+        let count = counts.entry(color.into()).or_insert(0);
+        *count = (*count).max(shown);
+    }
+    Bag { counts }
+}
+
+// the bag the reveals were most likely drawn from, and the log likelihood of the reveals with it
+#[derive(Debug, Clone)]
+pub struct Inference {
+    pub bag: Bag,
+    pub log_likelihood: f64,
+}
+
+// tries every size of bag from the smallest one the reveals could have come from up to max_cubes
+// (or the smallest bag's size, if that's bigger). each size starts from the best bag of the size
+// before with the cube added that helps the most, then keeps swapping a cube for one of another
+// color while that makes the reveals more likely. only colors the reveals show go in the bag, any
+// other cubes would only make them less likely. with no reveals at all, that's an empty bag.
+// drawing without replacement from a huge bag is nearly drawing with replacement, which often
+// fits the reveals better than any bag of a sensible size, so that's why there is a max_cubes
+pub fn infer_bag<'a>(reveals: impl IntoIterator<Item = &'a Reveal>, max_cubes: u32) -> Inference {
+    let reveals = reveals.into_iter().collect::<Vec<_>>();
+    let mut ln_factorials = LnFactorials::new();
+    let mut log_likelihood = |counts: &BTreeMap<Box<str>, u32>| {
+        log_likelihood_with(counts, &reveals, &mut ln_factorials)
+    };
+
+    let min_counts = smallest_bag(reveals.iter().copied()).counts;
+    let colors = min_counts.keys().collect::<Vec<_>>();
+    if colors.is_empty() {
+        return Inference {
+            bag: Bag::default(),
+            log_likelihood: 0.0,
+        };
+    }
+    let min_cubes = min_counts
+        .values()
+        .map(|&count| u64::from(count))
+        .sum::<u64>();
+
+    let mut counts = min_counts.clone();
+    let mut counts_log_likelihood = log_likelihood(&counts);
+    let mut best = (counts.clone(), counts_log_likelihood);
+
+    for num_cubes in min_cubes..=u64::from(max_cubes).max(min_cubes) {
+        if num_cubes > min_cubes {
+            (counts, counts_log_likelihood) = colors
+                .iter()
+                .map(|&color| {
+                    let mut candidate = counts.clone();
+                    *candidate.get_mut(color).unwrap() += 1;
+                    let candidate_log_likelihood = log_likelihood(&candidate);
+                    (candidate, candidate_log_likelihood)
+                })
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .unwrap();
+        }
+
+        loop {
+            let mut best_swap = None;
+
+            for &add in &colors {
+                for &remove in &colors {
+                    if add == remove || counts[remove] == min_counts[remove] {
+                        continue;
+                    }
+
+                    let mut candidate = counts.clone();
+                    *candidate.get_mut(add).unwrap() += 1;
+                    *candidate.get_mut(remove).unwrap() -= 1;
+
+                    let candidate_log_likelihood = log_likelihood(&candidate);
+                    let best_so_far = best_swap
+                        .as_ref()
+                        .map_or(counts_log_likelihood, |(_, best)| *best);
+                    if candidate_log_likelihood > best_so_far + EPSILON {
+                        best_swap = Some((candidate, candidate_log_likelihood));
+                    }
+                }
+            }
+
+            match best_swap {
+                Some(swapped) => (counts, counts_log_likelihood) = swapped,
+                None => break,
+            }
+        }
+
+        if counts_log_likelihood > best.1 + EPSILON {
+            best = (counts.clone(), counts_log_likelihood);
+        }
+    }
+
+    let (counts, log_likelihood) = best;
+    Inference {
+        bag: Bag { counts },
+        log_likelihood,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reveals(reveals: &[&str]) -> Vec<Reveal> {
+        reveals
+            .iter()
+            .map(|&reveal| Reveal::try_from(reveal).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_log_likelihood() {
+        let bag = Bag::try_from("2 red, 1 blue").unwrap();
+
+        // 2 of the 3 ways to draw one cube give red
+        let one_red = reveals(&["1 red"]);
+        assert!((log_likelihood(&bag, &one_red) - (2.0f64 / 3.0).ln()).abs() < 1e-12);
+
+        // the only way to draw both reds, twice over
+        let two_reds = reveals(&["2 red", "2 red"]);
+        assert!((log_likelihood(&bag, &two_reds) - 2.0 * (1.0f64 / 3.0).ln()).abs() < 1e-12);
+
+        let too_many = reveals(&["1 red", "2 blue"]);
+        assert_eq!(log_likelihood(&bag, &too_many), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_ln_factorials() {
+        let mut ln_factorials = LnFactorials::new();
+        let summed = (1..=2 * TABLE_LEN).map(|i| (i as f64).ln()).sum::<f64>();
+        assert!((ln_factorials.get(2 * TABLE_LEN) - summed).abs() < 1e-9);
+
+        // 2000000000 * 1999999999 / 2 ways to pick 2 cubes out of 2000000000
+        let ln_choose = ln_factorials.ln_choose(2_000_000_000, 2);
+        assert!((ln_choose - (2e9f64 * 1999999999.0 / 2.0).ln()).abs() < 1e-9);
+        assert!(ln_factorials.0.len() <= TABLE_LEN);
+    }
+
+    #[test]
+    fn test_infer_bag() {
+        // showing all of the bag every time is most likely when that's all there is
+        let inference = infer_bag(&reveals(&["2 red, 1 blue", "2 red, 1 blue"]), 100);
+        assert_eq!(inference.bag.to_string(), "1 blue, 2 red");
+        assert_eq!(inference.log_likelihood, 0.0);
+
+        // lots of single reds and only one blue point to more reds than were ever shown at once
+        let mut draws = vec!["1 red"; 9];
+        draws.push("1 blue");
+        let draws = reveals(&draws);
+        let inference = infer_bag(&draws, 100);
+        // (n / (n + 1))^9 / (n + 1) is highest with n = 9 reds
+        assert_eq!(inference.bag.to_string(), "1 blue, 9 red");
+
+        let smallest = Bag::try_from("1 red, 1 blue").unwrap();
+        assert!(inference.log_likelihood > log_likelihood(&smallest, &draws));
+
+        // with room for 5 cubes, the reds can only go up to 4
+        assert_eq!(infer_bag(&draws, 5).bag.to_string(), "1 blue, 4 red");
+
+        // n reds and n blues make these more likely the bigger n is, without ever getting to
+        // likelier than 1 in 32, so the bag fills up to the 8 cubes there's room for
+        let draws = reveals(&["2 red", "2 blue", "1 red, 1 blue"]);
+        let inference = infer_bag(&draws, 8);
+        assert_eq!(inference.bag.to_string(), "4 blue, 4 red");
+        assert!(inference.log_likelihood < (1.0f64 / 32.0).ln());
+
+        // no reveals, nothing to go on
+        let inference = infer_bag(&[], 100);
+        assert_eq!(inference.bag.to_string(), "");
+        assert_eq!(inference.log_likelihood, 0.0);
+
+        // more cubes than there's room for are still shown, so they are all in the bag
+        let draws = reveals(&["2000000000 red", "2000000000 blue"]);
+        let inference = infer_bag(&draws, 100);
+        assert_eq!(inference.bag.to_string(), "2000000000 blue, 2000000000 red");
+        assert!(inference.log_likelihood.is_finite());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{parse_game, Bag, Game};
+use anyhow::{Context, Result};
+use std::{
+    collections::{BTreeSet, HashSet},
+    io::BufRead,
+};
+
+// the games in a log, parsed one line at a time so only the line being parsed is kept in memory.
+// repeated ids aren't checked for unless asked with check_repeated_ids, as that also keeps every
+// id seen so far
+pub struct Games<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    // None unless repeated ids are checked for
+    ids: Option<HashSet<i32>>,
+}
+
+impl<R: BufRead> Games<R> {
+    pub fn new(reader: R) -> Self {
+        Games {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            ids: None,
+        }
+    }
+
+    pub fn check_repeated_ids(mut self) -> Self {
+        self.ids = Some(HashSet::new());
+        self
+    }
+}
+
+impl<R: BufRead> Iterator for Games<R> {
+    type Item = Result<Game>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            self.line_number += 1;
+
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(err) => {
+                    let context = format!("Unable to read line {}", self.line_number);
+                    return Some(Err(err).context(context));
+                }
+            }
+
+            let line = self.line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                continue;
+            }
+
+            // errors are located within the line, then moved down to where the line is
+            return Some(parse_game(line, self.ids.as_mut()).map_err(|err| {
+                let mut err = err.locate(line);
+                err.line = self.line_number;
+                err.into()
+            }));
+        }
+    }
+}
+
+// part 1's answer added up a game at a time, in i64 as a big enough log's ids overflow an i32
+#[derive(Debug, Clone)]
+pub struct SumPossible {
+    bag: Bag,
+    sum: i64,
+}
+
+impl SumPossible {
+    pub fn new(bag: Bag) -> Self {
+        SumPossible { bag, sum: 0 }
+    }
+
+    pub fn add(&mut self, game: &Game) {
+        if game.possible_with(&self.bag) {
+            self.sum += i64::from(game.id);
+        }
+    }
+
+    pub fn sum(&self) -> i64 {
+        self.sum
+    }
+}
+
+impl Default for SumPossible {
+    fn default() -> Self {
+        SumPossible::new(Bag::part1())
+    }
+}
+
+// part 2's answer added up a game at a time. like part2, a game without one of the colors in the
+// palette has a power of 0, so when a game brings a new color every game before it stops counting
+#[derive(Debug, Clone, Default)]
+pub struct SumPowers {
+    palette: BTreeSet<Box<str>>,
+    sum: u64,
+}
+
+impl SumPowers {
+    pub fn add(&mut self, game: &Game) -> Result<()> {
+        let min_counts = game.get_min_possible_counts();
+
+        let mut new_color = false;
+        for color in min_counts.colors() {
+            if !self.palette.contains(color) {
+                new_color |= self.palette.insert(color.into());
+            }
+        }
+        if new_color {
+            self.sum = 0;
+        }
+
+        self.sum = min_counts
+            .power(self.palette.iter().map(|color| &**color))
+            .and_then(|power| self.sum.checked_add(power))
+            .with_context(|| format!("the powers overflow a u64 at game {}", game.id))?;
+        Ok(())
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::ParseError;
+
+    const TEST_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+                              Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+                              \n\
+                              Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\r\n\
+                              Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+                              Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n";
+
+    fn sums(input: &str) -> (i64, u64) {
+        let mut possible = SumPossible::default();
+        let mut powers = SumPowers::default();
+        for game in Games::new(input.as_bytes()) {
+            let game = game.unwrap();
+            possible.add(&game);
+            powers.add(&game).unwrap();
+        }
+        (possible.sum(), powers.sum())
+    }
+
+    #[test]
+    fn test_sums() {
+        assert_eq!(sums(TEST_INPUT), (8, 2286));
+
+        // the first game's purple counts until the second shows there is also yellow, which the
+        // first didn't have, and the third has no yellow either
+        let input = "Game 1: 2 red, 3 purple\n\
+                     Game 2: 1 red, 2 yellow, 4 purple\n\
+                     Game 3: 5 red, 2 purple";
+        assert_eq!(sums(input), (0, 8));
+
+        let input = "Game 1: 4000000000 red, 4000000000 green, 4000000000 blue";
+        let mut powers = SumPowers::default();
+        let game = Games::new(input.as_bytes()).next().unwrap().unwrap();
+        let err = powers.add(&game).unwrap_err();
+        assert_eq!(err.to_string(), "the powers overflow a u64 at game 1");
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "Game 1: 3 blue, 4 red\n\nGame 2: 1 blue, two green";
+        let err = Games::new(input.as_bytes())
+            .find_map(Result::err)
+            .unwrap()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!(err, ParseError::at(3, 17, "two", "expected a number"));
+
+        let input = "Game 1: 3 blue\nGame 2: 4 red\nGame 1: 1 green";
+        let games = Games::new(input.as_bytes());
+        assert_eq!(
+            games.map(|game| game.unwrap().id()).collect::<Vec<_>>(),
+            [1, 2, 1]
+        );
+
+        let err = Games::new(input.as_bytes())
+            .check_repeated_ids()
+            .find_map(Result::err)
+            .unwrap()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!(
+            err,
+            ParseError::at(
+                3,
+                1,
+                "Game 1",
+                "expected each game id once, game 1 is repeated"
+            )
+        );
+    }
+}
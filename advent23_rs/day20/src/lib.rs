@@ -0,0 +1,475 @@
+use anyhow::{bail, Context, Result};
+use common::{CancellationToken, Cancelled, ParseError, Solution};
+use grid::Grid;
+use image::{Image, Rgb};
+use num::integer::lcm;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    iter,
+    ops::{Deref, DerefMut},
+};
+
+mod utils;
+use utils::{Broadcaster, Button, Conjunction, FlipFlop, Module, ModuleEnum, Pulse, PulsePacket};
+
+enum ModuleOrPlaceholder<'a> {
+    Module(ModuleEnum<'a>),
+    ConjunctionPlaceholder {
+        module_name: &'a str,
+        successors: Box<[&'a str]>,
+        predecessors: Vec<&'a str>,
+    },
+}
+
+impl<'a> ModuleOrPlaceholder<'a> {
+    fn new_placeholder(module_name: &'a str, successors: Box<[&'a str]>) -> Self {
+        ModuleOrPlaceholder::ConjunctionPlaceholder {
+            module_name,
+            successors,
+            predecessors: Vec::new(),
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Result<ModuleOrPlaceholder<'_>, ParseError> {
+    let (module_description, successors_str) = line
+        .split_once(" -> ")
+        .ok_or_else(|| ParseError::new(line, "expected a module like \"%a -> inv, con\""))?;
+
+    let successors = successors_str.split(", ").collect();
+
+    if module_description == "broadcaster" {
+        return Ok(ModuleOrPlaceholder::Module(
+            Broadcaster::new(successors).into(),
+        ));
+    }
+
+    if let Some(module_name) = module_description.strip_prefix('%') {
+        Ok(ModuleOrPlaceholder::Module(
+            FlipFlop::new(module_name, successors).into(),
+        ))
+    } else if let Some(module_name) = module_description.strip_prefix('&') {
+        Ok(ModuleOrPlaceholder::new_placeholder(
+            module_name,
+            successors,
+        ))
+    } else {
+        Err(ParseError::new(
+            module_description,
+            "expected \"broadcaster\", or a module name starting with '%' or '&'",
+        ))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<HashMap<&str, ModuleEnum<'_>>, ParseError> {
+    let temp_graph: HashMap<&str, RefCell<ModuleOrPlaceholder>> = input
+        .lines()
+        .map(|line| {
+            let module_or_placeholder = parse_line(line)?;
+            let module_name = match &module_or_placeholder {
+                ModuleOrPlaceholder::Module(module) => module.name(),
+                &ModuleOrPlaceholder::ConjunctionPlaceholder { module_name, .. } => module_name,
+            };
+            Ok((module_name, RefCell::new(module_or_placeholder)))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    if !temp_graph.contains_key("broadcaster") {
+        return Err(ParseError::message("expected a broadcaster module"));
+    }
+
+    for (&module_name, module_or_placeholder_refcell) in temp_graph.iter() {
+        let module_or_placeholder_ref = module_or_placeholder_refcell.borrow();
+        let successors = match module_or_placeholder_ref.deref() {
+            ModuleOrPlaceholder::Module(module) => module.successors(),
+            ModuleOrPlaceholder::ConjunctionPlaceholder { successors, .. } => successors,
+        };
+
+        for &successor in successors {
+            if let Some(other_module_or_placeholder_refcell) = temp_graph.get(successor) {
+                let mut succ_module_ref = other_module_or_placeholder_refcell.borrow_mut();
+                if let ModuleOrPlaceholder::ConjunctionPlaceholder { predecessors, .. } =
+                    succ_module_ref.deref_mut()
+                {
+                    predecessors.push(module_name);
+                }
+            }
+        }
+    }
+
+    // convert result into correct type by copying everything over
+    let mut result: HashMap<&str, ModuleEnum> = temp_graph
+        .into_iter()
+        .map(|(module_name, module_or_placeholder_refcell)| {
+            let module = match module_or_placeholder_refcell.into_inner() {
+                ModuleOrPlaceholder::Module(module) => module,
+                ModuleOrPlaceholder::ConjunctionPlaceholder {
+                    successors,
+                    mut predecessors,
+                    ..
+                } => {
+                    // they were found in hash order, sorting them parses an input the same way
+                    // every time
+                    predecessors.sort_unstable();
+                    Conjunction::new(module_name, successors, predecessors.into_boxed_slice())
+                        .into()
+                }
+            };
+            (module_name, module)
+        })
+        .collect();
+
+    result.insert("button", Button.into());
+
+    Ok(result)
+}
+
+// deliver the pulse at the front of the queue, None once the queue is empty
+fn deliver_pulse<'a>(
+    graph: &mut HashMap<&'a str, ModuleEnum<'a>>,
+    queue: &mut VecDeque<PulsePacket<'a>>,
+) -> Option<PulsePacket<'a>> {
+    let packet = queue.pop_front()?;
+
+    if let Some(module) = graph.get_mut(&packet.destination) {
+        module.process_pulse(packet.source, packet.pulse, queue);
+    }
+
+    Some(packet)
+}
+
+// press the button once and deliver every pulse it sets off, on_pulse sees each one as it's delivered
+fn press_button<'a>(
+    graph: &mut HashMap<&'a str, ModuleEnum<'a>>,
+    mut on_pulse: impl FnMut(&PulsePacket<'a>),
+) {
+    let mut queue = VecDeque::new();
+    Button.send_to_successors(Pulse::Low, &mut queue);
+
+    while let Some(packet) = deliver_pulse(graph, &mut queue) {
+        on_pulse(&packet);
+    }
+}
+
+pub fn part1<'a>(mut graph: HashMap<&'a str, ModuleEnum<'a>>) -> usize {
+    let mut num_low = 0;
+    let mut num_high = 0;
+
+    for _ in 0..1000 {
+        press_button(&mut graph, |packet| match packet.pulse {
+            Pulse::Low => num_low += 1,
+            Pulse::High => num_high += 1,
+        });
+    }
+
+    num_low * num_high
+}
+
+// which modules send pulses to which, modules without a line of their own like rx included
+pub fn to_graph<'a>(modules: &HashMap<&'a str, ModuleEnum<'a>>) -> graph::Graph<&'a str> {
+    // sorted so the ids don't depend on the order of the hash map
+    let mut names = modules.keys().copied().collect::<Vec<_>>();
+    names.sort_unstable();
+
+    let mut graph = graph::Graph::new();
+    for name in names {
+        let from = graph.intern(name);
+        for &successor in modules[name].successors() {
+            let to = graph.intern(successor);
+            graph.add_edge(from, to, 1);
+        }
+    }
+
+    graph
+}
+
+// the modules that can send pulses to module, including itself. reversed is to_graph reversed
+fn ancestors<'a>(
+    modules: &HashMap<&'a str, ModuleEnum<'a>>,
+    reversed: &graph::Graph<&'a str>,
+    module: &'a str,
+) -> HashMap<&'a str, ModuleEnum<'a>> {
+    reversed
+        .dfs(reversed.id(&module).unwrap())
+        .into_iter()
+        .map(|id| {
+            let name = *reversed.name(id);
+            (name, modules[name].clone())
+        })
+        .collect()
+}
+
+pub fn part2(graph: &HashMap<&str, ModuleEnum>, cancel: &CancellationToken) -> Result<usize> {
+    // assumptions:
+    // 1. fair assumption, a conjunction (jz in my input) -> rx is the only (low)
+    // pulse to rx possible
+    // 2. All of its predecessors run on separate cycles, which do not interact
+    // with each other, and each sends a high signal and then a low signal once
+    // at the end of each cycle. the cycles are found on each predecessor's part
+    // of the network, and 2 is checked rather than trusted
+
+    // for example some output of all signals sent to jz
+    // where i is the number of button presses:
+
+    // source = vf, destination = jz, i = 3847, switched to High
+    // source = vf, destination = jz, i = 3847, switched to Low
+    // source = rn, destination = jz, i = 3923, switched to High
+    // source = rn, destination = jz, i = 3923, switched to Low
+    // source = dh, destination = jz, i = 4001, switched to High
+    // source = dh, destination = jz, i = 4001, switched to Low
+    // source = mk, destination = jz, i = 4091, switched to High
+    // source = mk, destination = jz, i = 4091, switched to Low
+    // source = vf, destination = jz, i = 7694, switched to High
+    // source = vf, destination = jz, i = 7694, switched to Low
+    // source = rn, destination = jz, i = 7846, switched to High
+    // source = rn, destination = jz, i = 7846, switched to Low
+    // source = dh, destination = jz, i = 8002, switched to High
+    // source = dh, destination = jz, i = 8002, switched to Low
+    // source = mk, destination = jz, i = 8182, switched to High
+    // source = mk, destination = jz, i = 8182, switched to Low
+    // source = vf, destination = jz, i = 11541, switched to High
+    // source = vf, destination = jz, i = 11541, switched to Low
+    // source = rn, destination = jz, i = 11769, switched to High
+    // source = rn, destination = jz, i = 11769, switched to Low
+    // source = dh, destination = jz, i = 12003, switched to High
+    // source = dh, destination = jz, i = 12003, switched to Low
+    // source = mk, destination = jz, i = 12273, switched to High
+    // source = mk, destination = jz, i = 12273, switched to Low
+
+    let feeders = graph
+        .values()
+        .filter(|module| module.successors().contains(&"rx"))
+        .collect::<Vec<_>>();
+
+    let [ModuleEnum::Conjunction(feeder)] = feeders[..] else {
+        bail!("expected a single conjunction module to send to rx");
+    };
+
+    let reversed = to_graph(graph).reversed();
+    let mut cycle_lengths = Vec::new();
+    // every press so far, for cancel. a predecessor can take forever to repeat
+    let mut num_presses = 0;
+
+    for &predecessor in feeder.predecessors() {
+        // the modules which predecessor depends on, their state repeats on its own
+        let subgraph = ancestors(graph, &reversed, predecessor);
+
+        let cycle = cycle::try_brent(&subgraph, |subgraph| {
+            cancel.tick(num_presses)?;
+            num_presses += 1;
+            press_button(subgraph, |_| ());
+            Ok::<_, Cancelled>(())
+        })?;
+
+        // press through the prefix and a whole cycle to see when predecessor fires
+        let mut state = subgraph;
+        let mut high_presses = Vec::new();
+        for num_button_presses in 1..=cycle.prefix_len + cycle.period {
+            cancel.tick(num_presses)?;
+            num_presses += 1;
+            press_button(&mut state, |packet| {
+                if packet.source == predecessor
+                    && packet.destination == feeder.name()
+                    && packet.pulse == Pulse::High
+                {
+                    high_presses.push(num_button_presses);
+                }
+            });
+        }
+
+        // a high pulse on the last press of each cycle and never otherwise, so it
+        // fires on every multiple of the cycle length
+        if high_presses != [cycle.period] || cycle.period <= cycle.prefix_len {
+            bail!(
+                "{predecessor} does not send {} a single high pulse at the end of every cycle",
+                feeder.name()
+            );
+        }
+
+        cycle_lengths.push(cycle.period);
+    }
+
+    cycle_lengths
+        .into_iter()
+        .reduce(lcm)
+        .context("expected the conjunction sending to rx to have inputs")
+}
+
+// a pixel per module in order of name, filling rows of a square. flip-flops are yellow when on,
+// conjunctions get greener the more of their inputs were high, and the module a pulse just reached
+// is red for a high pulse or blue for a low one
+fn draw(graph: &HashMap<&str, ModuleEnum>, names: &[&str], packet: &PulsePacket) -> Image {
+    let num_columns = (1..).find(|n| n * n >= names.len()).unwrap();
+    let num_rows = names.len().div_ceil(num_columns);
+
+    let color = |name: &str| -> Rgb {
+        if name == packet.destination {
+            return match packet.pulse {
+                Pulse::High => [240, 70, 60],
+                Pulse::Low => [70, 140, 250],
+            };
+        }
+
+        match graph.get(name) {
+            Some(ModuleEnum::FlipFlop(flip_flop)) if flip_flop.is_on() => [250, 210, 50],
+            Some(ModuleEnum::FlipFlop(_)) => [70, 60, 20],
+            Some(ModuleEnum::Conjunction(conjunction)) => image::lerp(
+                [20, 60, 20],
+                [80, 230, 80],
+                conjunction.num_high() as f64 / conjunction.predecessors().len() as f64,
+            ),
+            // the broadcaster, and modules like rx that don't send anything
+            _ => [110, 110, 110],
+        }
+    };
+
+    Grid::from_iter_row_major(
+        names
+            .iter()
+            .map(|name| color(name))
+            .chain(iter::repeat(image::BLACK))
+            .take(num_rows * num_columns),
+        num_rows,
+        num_columns,
+    )
+}
+
+// a frame for every pulse delivered, pressing the button again whenever the pulses stop, up to
+// num_presses times or forever if that's None
+pub fn frames<'a>(
+    mut graph: HashMap<&'a str, ModuleEnum<'a>>,
+    num_presses: Option<usize>,
+) -> impl Iterator<Item = Image> + 'a {
+    let modules = to_graph(&graph);
+    let mut names = modules
+        .nodes()
+        .map(|id| *modules.name(id))
+        .filter(|&name| name != "button")
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+
+    let mut queue = VecDeque::new();
+    let mut num_pressed = 0;
+
+    iter::from_fn(move || {
+        if queue.is_empty() {
+            if num_presses == Some(num_pressed) {
+                return None;
+            }
+            Button.send_to_successors(Pulse::Low, &mut queue);
+            num_pressed += 1;
+        }
+
+        let packet = deliver_pulse(&mut graph, &mut queue)?;
+        Some(draw(&graph, &names, &packet))
+    })
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'a> = HashMap<&'a str, ModuleEnum<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    // part 1 presses the button on its own copy of the modules
+    fn part1(graph: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part1(graph.clone()))
+    }
+
+    fn part2(graph: &Self::Parsed<'_>) -> Result<usize> {
+        Self::part2_cancellable(graph, &CancellationToken::new())
+    }
+
+    fn part2_cancellable(graph: &Self::Parsed<'_>, cancel: &CancellationToken) -> Result<usize> {
+        part2(graph, cancel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_A: &str = "broadcaster -> a, b, c\n\
+                             %a -> b\n\
+                             %b -> c\n\
+                             %c -> inv\n\
+                             &inv -> a";
+
+    const EXAMPLE_B: &str = "broadcaster -> a\n\
+                             %a -> inv, con\n\
+                             &inv -> b\n\
+                             %b -> con\n\
+                             &con -> output";
+
+    #[test]
+    fn test_part1_a() {
+        let graph = parse_input(EXAMPLE_A).unwrap();
+
+        assert_eq!(32000000, part1(graph));
+    }
+
+    #[test]
+    fn test_part1_b() {
+        let graph = parse_input(EXAMPLE_B).unwrap();
+
+        assert_eq!(11687500, part1(graph));
+    }
+
+    #[test]
+    fn test_part2_cancelled() {
+        // the flip-flops count button presses in binary, so con only sees them all high after
+        // 2^41 - 1 presses and they take 2^41 presses to repeat
+        let mut input = String::from("broadcaster -> f0\n");
+        for i in 0..40 {
+            input += &format!("%f{i} -> f{}, con\n", i + 1);
+        }
+        input += "%f40 -> con\n&con -> feed\n&feed -> rx";
+        let graph = parse_input(&input).unwrap();
+
+        let cancel = CancellationToken::new().with_timeout(std::time::Duration::from_millis(100));
+        let err = part2(&graph, &cancel).unwrap_err();
+        assert!(
+            err.to_string().starts_with("timed out after 100ms"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_frames() {
+        // the 12 pulses of a press, ending with inv sending a high pulse to a once a, b and c
+        // have all flipped on and back off again
+        let graph = parse_input(EXAMPLE_A).unwrap();
+        let frames = frames(graph, Some(1)).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 12);
+
+        // a, b, broadcaster / c, inv
+        let last = frames.last().unwrap();
+        assert_eq!((last.num_rows(), last.num_columns()), (2, 3));
+        assert_eq!(last[(0, 0)], [240, 70, 60]);
+        assert_eq!(last[(0, 1)], [70, 60, 20]);
+        assert_eq!(last[(0, 2)], [110, 110, 110]);
+        assert_eq!(last[(1, 0)], [70, 60, 20]);
+        assert_eq!(last[(1, 1)], [20, 60, 20]);
+        assert_eq!(last[(1, 2)], image::BLACK);
+
+        // the 5th pulse is a's high pulse to b, after the broadcaster has flipped a, b and c on
+        assert_eq!(frames[4][(1, 0)], [250, 210, 50]);
+        assert_eq!(frames[4][(0, 1)], [240, 70, 60]);
+    }
+
+    #[test]
+    fn test_parse_predecessors() {
+        let graph = parse_input(EXAMPLE_B).unwrap();
+
+        let ModuleEnum::Conjunction(con) = &graph["con"] else {
+            panic!("con should be a conjunction");
+        };
+        assert_eq!(con.predecessors(), ["a", "b"]);
+    }
+}
@@ -1,8 +1,9 @@
 use enum_dispatch::enum_dispatch;
 use lazy_static::lazy_static;
+use serde::Serialize;
 use std::collections::VecDeque;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum Pulse {
     Low,
     High,
@@ -35,7 +36,7 @@ pub trait Module<'a> {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub struct FlipFlop<'a> {
     name: &'a str,
     successors: Box<[&'a str]>,
@@ -52,6 +53,12 @@ impl<'a> FlipFlop<'a> {
     }
 }
 
+impl FlipFlop<'_> {
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+}
+
 impl<'a> Module<'a> for FlipFlop<'a> {
     fn name(&self) -> &'a str {
         self.name
@@ -78,7 +85,7 @@ impl<'a> Module<'a> for FlipFlop<'a> {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub struct Conjunction<'a> {
     // most_recent_pulses: HashMap<&'a str, Pulse>,
     name: &'a str,
@@ -100,6 +107,14 @@ impl<'a> Conjunction<'a> {
     pub fn predecessors(&self) -> &[&'a str] {
         &self.predecessors
     }
+
+    // how many predecessors sent a high pulse last
+    pub fn num_high(&self) -> usize {
+        self.most_recent_pulses
+            .iter()
+            .filter(|&&pulse| pulse == Pulse::High)
+            .count()
+    }
 }
 
 impl<'a> Module<'a> for Conjunction<'a> {
@@ -132,7 +147,7 @@ impl<'a> Module<'a> for Conjunction<'a> {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub struct Broadcaster<'a> {
     successors: Box<[&'a str]>,
 }
@@ -162,7 +177,7 @@ impl<'a> Module<'a> for Broadcaster<'a> {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub struct Button;
 
 impl Module<'_> for Button {
@@ -184,7 +199,7 @@ impl Module<'_> for Button {
 }
 
 #[enum_dispatch]
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Serialize)]
 pub enum ModuleEnum<'a> {
     FlipFlop(FlipFlop<'a>),
     Conjunction(Conjunction<'a>),
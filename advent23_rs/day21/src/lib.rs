@@ -0,0 +1,320 @@
+use anyhow::{ensure, Result};
+use common::{ParseError, Solution};
+use grid::{Grid, Position};
+use image::Image;
+use serde::Serialize;
+use std::collections::HashSet;
+
+#[derive(Clone, Serialize)]
+pub enum Tile {
+    GardenPlot,
+    Rock,
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            b'.' => Ok(Tile::GardenPlot),
+            b'#' => Ok(Tile::Rock),
+            b'S' => Ok(Tile::GardenPlot),
+            _ => Err(value),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Tile>, ParseError> {
+    let grid = Grid::parse(input)?;
+
+    // the start is not kept in the grid, find_start relies on it being in the center
+    let (row, line) = input
+        .lines()
+        .enumerate()
+        .find(|(_, line)| line.contains('S'))
+        .ok_or_else(|| ParseError::message("expected the map to contain a start 'S'"))?;
+    let column = line.find('S').unwrap();
+
+    if (row, column) != find_start(&grid) {
+        return Err(ParseError::new(
+            &line[column..=column],
+            "expected the start to be in the center of the map",
+        ));
+    }
+
+    Ok(grid)
+}
+
+fn find_start(grid: &Grid<Tile>) -> Position {
+    (grid.num_rows() / 2, grid.num_columns() / 2)
+}
+
+fn get_successors(grid: &Grid<Tile>, point: Position) -> impl Iterator<Item = Position> + '_ {
+    grid.neighbors4(point)
+        .map(|(_, point)| point)
+        .filter(|&point| !matches!(grid[point], Tile::Rock))
+}
+
+// repeat the grid times x times copies of itself
+fn tile_grid(grid: &Grid<Tile>, times: usize) -> Grid<Tile> {
+    let num_rows = times * grid.num_rows();
+    let num_columns = times * grid.num_columns();
+
+    Grid::from_iter_row_major(
+        (0..num_rows).flat_map(|row| {
+            (0..num_columns).map(move |column| {
+                grid[(row % grid.num_rows(), column % grid.num_columns())].clone()
+            })
+        }),
+        num_rows,
+        num_columns,
+    )
+}
+
+// the plots reachable in one more step than the ones in queue
+fn step(grid: &Grid<Tile>, queue: &HashSet<Position>) -> HashSet<Position> {
+    let mut next_queue = HashSet::new();
+
+    for &point in queue {
+        next_queue.extend(get_successors(grid, point));
+    }
+
+    next_queue
+}
+
+pub fn get_reachable_gardens(grid: &Grid<Tile>, distance: usize) -> HashSet<Position> {
+    let start_pt = find_start(grid);
+
+    (0..distance).fold(HashSet::from([start_pt]), |queue, _| step(grid, &queue))
+}
+
+// enough copies of the map for num_steps from the start to stay inside
+fn tile_grid_for(grid: &Grid<Tile>, num_steps: usize) -> Grid<Tile> {
+    let copies_each_side = |len: usize| num_steps.saturating_sub(len / 2).div_ceil(len);
+    let times = 2 * copies_each_side(grid.num_rows()).max(copies_each_side(grid.num_columns())) + 1;
+
+    tile_grid(grid, times)
+}
+
+// the reachable plots in green and the rocks in grey
+fn draw(grid: &Grid<Tile>, reachable: &HashSet<Position>) -> Image {
+    grid.map_with_position(|position, tile| match tile {
+        Tile::Rock => [100, 100, 100],
+        Tile::GardenPlot if reachable.contains(&position) => [80, 200, 80],
+        Tile::GardenPlot => [20, 50, 20],
+    })
+}
+
+// the plots reachable in exactly num_steps
+pub fn render(grid: &Grid<Tile>, num_steps: usize) -> Image {
+    let grid = tile_grid_for(grid, num_steps);
+    let reachable = get_reachable_gardens(&grid, num_steps);
+
+    draw(&grid, &reachable)
+}
+
+// the plots reachable in exactly 0, 1, 2, ... num_steps, a frame per step
+pub fn frames(grid: &Grid<Tile>, num_steps: usize) -> impl Iterator<Item = Image> {
+    let grid = tile_grid_for(grid, num_steps);
+    let mut reachable = HashSet::from([find_start(&grid)]);
+
+    (0..=num_steps).map(move |i| {
+        if i > 0 {
+            reachable = step(&grid, &reachable);
+        }
+        draw(&grid, &reachable)
+    })
+}
+
+pub fn part2(grid: &Grid<Tile>, num_steps: usize) -> Result<usize> {
+    // assumptions:
+    // num_steps = q * grid.num_rows() + grid.num_rows() / 2
+    // and grid.num_rows() is odd
+    // where q is even and not 0
+    // and grid.num_rows() == grid.num_columns()
+
+    let n = grid.num_rows();
+
+    ensure!(
+        n == grid.num_columns(),
+        "expected a square map, not {} by {}",
+        n,
+        grid.num_columns()
+    );
+    ensure!(
+        n % 2 == 1,
+        "expected the map to be an odd number of plots across, not {n}"
+    );
+
+    let q = num_steps / n;
+
+    ensure!(
+        num_steps % n == n / 2,
+        "expected {num_steps} steps to end on the edge of a copy of the map, {} steps from the \
+         start, not {}",
+        n / 2,
+        num_steps % n
+    );
+    ensure!(
+        q > 0 && q.is_multiple_of(2),
+        "expected {num_steps} steps to cross an even number of whole copies of the map, not {q}"
+    );
+
+    let grid_times_5 = tile_grid(grid, 5);
+
+    let reachable_on_times_5 = get_reachable_gardens(&grid_times_5, 5 * n / 2);
+
+    let full_square = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .collect::<Box<_>>();
+
+    let shift_and_get_num_reachable = |row_shift, column_shift| {
+        full_square
+            .iter()
+            .map(|&(i, j)| (i + row_shift * n, j + column_shift * n))
+            .filter(|point| reachable_on_times_5.contains(point))
+            .count()
+    };
+
+    /*
+     * Divide the rhombus of 5
+     * grids into this shape:
+     *
+     *   A
+     *  WEX
+     * DEOEB
+     *  ZEY
+     *   C
+     *
+     * also X is surrounded by 2 X primes
+     * same with Y, Z, W
+     *
+     * The final rhombus will have the amount of
+     * each letter described below
+     *
+     */
+
+    let o = shift_and_get_num_reachable(2, 2);
+    let e = shift_and_get_num_reachable(1, 2);
+
+    let a = shift_and_get_num_reachable(0, 2);
+    let b = shift_and_get_num_reachable(2, 4);
+    let c = shift_and_get_num_reachable(4, 2);
+    let d = shift_and_get_num_reachable(2, 0);
+
+    let x = shift_and_get_num_reachable(1, 3);
+    let y = shift_and_get_num_reachable(3, 3);
+    let z = shift_and_get_num_reachable(3, 1);
+    let w = shift_and_get_num_reachable(1, 1);
+
+    let x_prime = shift_and_get_num_reachable(0, 3);
+    let y_prime = shift_and_get_num_reachable(3, 4);
+    let z_prime = shift_and_get_num_reachable(4, 1);
+    let w_prime = shift_and_get_num_reachable(1, 0);
+
+    let o_count = (q - 1) * (q - 1);
+    let e_count = q * q;
+    let x_count = q - 1;
+    let x_prime_count = q;
+
+    Ok((a + b + c + d)
+        + o * o_count
+        + e * e_count
+        + (x + y + z + w) * x_count
+        + (x_prime + y_prime + z_prime + w_prime) * x_prime_count)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed<'a> = Grid<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(get_reachable_gardens(grid, 64).len())
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Result<usize> {
+        part2(grid, 26501365)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "...........\n\
+                              .....###.#.\n\
+                              .###.##..#.\n\
+                              ..#.#...#..\n\
+                              ....#.#....\n\
+                              .##..S####.\n\
+                              .##..#...#.\n\
+                              .......##..\n\
+                              .##.#.####.\n\
+                              .##..##.##.\n\
+                              ...........";
+
+    #[test]
+    fn test_part1() {
+        let grid = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(get_reachable_gardens(&grid, 6).len(), 16);
+    }
+
+    #[test]
+    fn test_frames() {
+        let grid = parse_input(TEST_INPUT).unwrap();
+        let frames = frames(&grid, 6).collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), 7);
+        assert_eq!(frames[6], render(&grid, 6));
+
+        // only the start is reachable before the first step
+        let num_reachable = |frame: &Image| {
+            frame
+                .iter()
+                .filter(|&&pixel| pixel == [80, 200, 80])
+                .count()
+        };
+        assert_eq!(num_reachable(&frames[0]), 1);
+        assert_eq!(num_reachable(&frames[6]), 16);
+    }
+
+    #[test]
+    fn test_part2_shape() {
+        let err = |input, num_steps| {
+            part2(&parse_input(input).unwrap(), num_steps)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            err("...\n.S.", 26501365),
+            "expected a square map, not 2 by 3"
+        );
+        assert_eq!(
+            err("..\n.S", 26501365),
+            "expected the map to be an odd number of plots across, not 2"
+        );
+        assert_eq!(
+            err(TEST_INPUT, 26501365),
+            "expected 26501365 steps to end on the edge of a copy of the map, 5 steps from the \
+             start, not 0"
+        );
+        assert_eq!(
+            err(TEST_INPUT, 16),
+            "expected 16 steps to cross an even number of whole copies of the map, not 1"
+        );
+
+        // 2 whole copies and then half of one more fits the shape
+        let grid = parse_input(".....\n.....\n..S..\n.....\n.....").unwrap();
+        let num_reachable = get_reachable_gardens(&tile_grid_for(&grid, 12), 12).len();
+        assert_eq!(part2(&grid, 12).unwrap(), num_reachable);
+    }
+}
@@ -0,0 +1,333 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+use grid::Grid;
+use image::{Image, Rgb};
+use serde::Serialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+struct Position {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+impl TryFrom<&str> for Position {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, ParseError> {
+        // value = "x,y,z" where x y and z are u32
+        let coordinates = value
+            .split(',')
+            .map(parse_number)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let [x, y, z] = coordinates[..] else {
+            return Err(ParseError::new(value, "expected a position like \"1,0,1\""));
+        };
+
+        Ok(Position { x, y, z })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Brick {
+    min: Position,
+    max: Position,
+}
+
+impl Brick {
+    fn horizontally_collides(&self, other: &Self) -> bool {
+        !(self.max.x < other.min.x
+            || other.max.x < self.min.x
+            || self.max.y < other.min.y
+            || other.max.y < self.min.y)
+    }
+
+    fn set_bottom_z(&mut self, z: u32) {
+        let height = self.max.z - self.min.z;
+
+        self.min.z = z;
+        self.max.z = z + height;
+    }
+}
+
+impl TryFrom<&str> for Brick {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, ParseError> {
+        // looks like: <min position>~<max position>
+        let (min_str, max_str) = value
+            .split_once('~')
+            .ok_or_else(|| ParseError::new(value, "expected a brick like \"1,0,1~1,2,1\""))?;
+
+        let min = Position::try_from(min_str)?;
+        let max = Position::try_from(max_str)?;
+
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            return Err(ParseError::new(
+                value,
+                "expected the first position to be the lowest corner of the brick",
+            ));
+        }
+
+        Ok(Brick { min, max })
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Brick>, ParseError> {
+    input.lines().map(Brick::try_from).collect()
+}
+
+// drops falling_brick onto the highest brick under it and returns where it landed. landed_bricks
+// is sorted by maximum Z, lowest first, and stays that way
+fn land(landed_bricks: &mut Vec<Brick>, mut falling_brick: Brick) -> &Brick {
+    // landed_bricks is in sorted order lowest max Z to highest max Z
+    // but we iterate in reverse to find the first thing hit, to_land_on
+    let to_land_on = landed_bricks
+        .iter()
+        .rev()
+        .find(|other_brick| falling_brick.horizontally_collides(other_brick));
+
+    let new_z_value = match to_land_on {
+        // land on the first brick that collided with
+        Some(brick_to_land_on) => brick_to_land_on.max.z + 1,
+        // doesn't collide with an existing brick, land on the ground
+        None => 1,
+    };
+
+    // land on to_land_on
+    falling_brick.set_bottom_z(new_z_value);
+
+    // add the brick to the list
+    let insert_index = match landed_bricks
+        .binary_search_by(|other_brick| other_brick.max.z.cmp(&falling_brick.max.z))
+    {
+        Ok(i) => i,
+        Err(i) => i,
+    };
+
+    landed_bricks.insert(insert_index, falling_brick);
+    &landed_bricks[insert_index]
+}
+
+pub fn drop_bricks(mut bricks: Vec<Brick>) -> Vec<Brick> {
+    // sort by minimum Z
+    bricks.sort_unstable_by_key(|brick| brick.max.z);
+
+    // create a list which is sorted by maximum Z value
+    let mut landed_bricks = Vec::new();
+
+    for falling_brick in bricks {
+        land(&mut landed_bricks, falling_brick);
+    }
+
+    // get landed_bricks in descending order of max z
+    landed_bricks.reverse();
+
+    landed_bricks
+}
+
+// the bricks seen from the side, looking along y with x going right and z going up. the ground is
+// the bottom row, and the bricks nearest the front hide the ones behind them
+fn draw(bricks: &[(Rgb, &Brick)], max_x: u32, max_z: u32) -> Image {
+    let mut image = Grid::filled_with(image::BLACK, max_z as usize + 1, max_x as usize + 1);
+    let mut depths = Grid::filled_with(u32::MAX, max_z as usize + 1, max_x as usize + 1);
+
+    for column in 0..=max_x as usize {
+        image[(max_z as usize, column)] = [110, 110, 110];
+    }
+
+    for &(color, brick) in bricks {
+        for z in brick.min.z..=brick.max.z {
+            for x in brick.min.x..=brick.max.x {
+                let position = ((max_z - z) as usize, x as usize);
+                if brick.min.y < depths[position] {
+                    depths[position] = brick.min.y;
+                    image[position] = color;
+                }
+            }
+        }
+    }
+
+    image
+}
+
+// a frame for every brick that lands, lowest first. the falling bricks are grey and the landed
+// ones each have a color of their own
+pub fn frames(mut bricks: Vec<Brick>) -> impl Iterator<Item = Image> {
+    const COLORS: [Rgb; 6] = [
+        [230, 90, 70],
+        [240, 170, 50],
+        [120, 200, 80],
+        [70, 170, 220],
+        [150, 110, 230],
+        [230, 110, 180],
+    ];
+
+    bricks.sort_unstable_by_key(|brick| brick.max.z);
+    let max_x = bricks.iter().map(|brick| brick.max.x).max().unwrap_or(0);
+    let max_z = bricks.iter().map(|brick| brick.max.z).max().unwrap_or(0);
+
+    let mut landed_bricks = Vec::new();
+    // the same bricks in the order they landed, which picks their colors
+    let mut landed_in_order = Vec::new();
+
+    (0..=bricks.len()).map(move |num_landed| {
+        if num_landed > 0 {
+            let brick = land(&mut landed_bricks, bricks[num_landed - 1].clone());
+            landed_in_order.push(brick.clone());
+        }
+
+        let bricks = landed_in_order
+            .iter()
+            .enumerate()
+            .map(|(i, brick)| (COLORS[i % COLORS.len()], brick))
+            .chain(
+                bricks[num_landed..]
+                    .iter()
+                    .map(|brick| ([90, 90, 90], brick)),
+            )
+            .collect::<Vec<_>>();
+
+        draw(&bricks, max_x, max_z)
+    })
+}
+
+// ASSUME: landed_bricks is sorted in terms of maximum Z in descending order!
+fn get_bricks_above_and_below(landed_bricks: &[Brick]) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+    // bricks_below[i] = a vector containing the indices of bricks below landed_bricks[i]
+    let mut bricks_above = vec![vec![]; landed_bricks.len()];
+    let mut bricks_below = vec![vec![]; landed_bricks.len()];
+
+    for (i, higher_brick) in landed_bricks.iter().enumerate() {
+        for (j, lower_brick) in landed_bricks[i + 1..].iter().enumerate() {
+            if lower_brick.max.z + 1 < higher_brick.min.z {
+                break;
+            }
+
+            if lower_brick.max.z + 1 == higher_brick.min.z
+                && lower_brick.horizontally_collides(higher_brick)
+            {
+                bricks_above[i + 1 + j].push(i);
+                bricks_below[i].push(i + 1 + j);
+            }
+        }
+    }
+
+    (bricks_above, bricks_below)
+}
+
+// ASSUME: landed_bricks is sorted in terms of maximum Z in descending order!
+pub fn num_disintegrateable(landed_bricks: &[Brick]) -> usize {
+    let (_, bricks_below) = get_bricks_above_and_below(landed_bricks);
+    let non_disintegrateable = bricks_below
+        .into_iter()
+        .filter_map(|below| {
+            if below.len() == 1 {
+                Some(below[0])
+            } else {
+                None
+            }
+        })
+        .collect::<HashSet<_>>();
+
+    landed_bricks.len() - non_disintegrateable.len()
+}
+
+// ASSUME: landed_bricks is sorted in terms of maximum Z in descending order!
+pub fn part2(landed_bricks: &[Brick]) -> usize {
+    let (bricks_above, bricks_below) = get_bricks_above_and_below(landed_bricks);
+
+    (0..landed_bricks.len())
+        .map(|i| {
+            let mut stack = vec![i];
+            let mut fallen = HashSet::new();
+
+            while let Some(cur_index) = stack.pop() {
+                fallen.insert(cur_index);
+
+                // bricks only fall when everything under them has fallen :O
+                for above_index in bricks_above[cur_index].iter().copied() {
+                    if bricks_below[above_index]
+                        .iter()
+                        .all(|sibling_index| fallen.contains(sibling_index))
+                    {
+                        stack.push(above_index);
+                    }
+                }
+            }
+
+            // don't count the starting node
+            // since only want to count which have fallen
+            // and start node was disintegrated
+            fallen.len() - 1
+        })
+        .sum()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    // the bricks as they are in the snapshot, before they have fallen
+    type Parsed<'a> = Vec<Brick>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(bricks: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(num_disintegrateable(&drop_bricks(bricks.clone())))
+    }
+
+    fn part2(bricks: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part2(&drop_bricks(bricks.clone())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "1,0,1~1,2,1\n\
+                              0,0,2~2,0,2\n\
+                              0,2,3~2,2,3\n\
+                              0,0,4~0,2,4\n\
+                              2,0,5~2,2,5\n\
+                              0,1,6~2,1,6\n\
+                              1,1,8~1,1,9";
+
+    #[test]
+    fn test_part1() {
+        let bricks = parse_input(TEST_INPUT).unwrap();
+        let landed_bricks = drop_bricks(bricks);
+
+        assert_eq!(num_disintegrateable(&landed_bricks), 5);
+    }
+
+    #[test]
+    fn test_part2() {
+        let bricks = parse_input(TEST_INPUT).unwrap();
+        let landed_bricks = drop_bricks(bricks);
+
+        assert_eq!(part2(&landed_bricks), 7);
+    }
+
+    #[test]
+    fn test_frames() {
+        let bricks = parse_input(TEST_INPUT).unwrap();
+        let frames = frames(bricks).collect::<Vec<_>>();
+
+        // before anything lands, then after each of the 7 bricks lands
+        assert_eq!(frames.len(), 8);
+        assert_eq!((frames[0].num_rows(), frames[0].num_columns()), (10, 3));
+        // g is the last brick in the air, a 2 high column at x = 1 that falls from z = 8 to
+        // z = 5, on top of f
+        assert_eq!(frames[6][(1, 1)], [90, 90, 90]);
+        assert_eq!(frames[7][(1, 1)], image::BLACK);
+        assert_eq!(frames[7][(4, 1)], [230, 90, 70]);
+        assert_eq!(frames[7][(9, 0)], [110, 110, 110]);
+    }
+}
@@ -0,0 +1,461 @@
+use anyhow::{Context, Result};
+use common::{CancellationToken, Cancelled, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+use graph::NodeId;
+use grid::{Direction, Grid, Position};
+use image::Image;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Serialize)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            b'.' => Ok(Tile::Path),
+            b'#' => Ok(Tile::Forest),
+            b'^' => Ok(Tile::Slope(Direction::North)),
+            b'>' => Ok(Tile::Slope(Direction::East)),
+            b'v' => Ok(Tile::Slope(Direction::South)),
+            b'<' => Ok(Tile::Slope(Direction::West)),
+            _ => Err(value),
+        }
+    }
+}
+
+// the hike starts in the second column of the top row and ends in the second to last column of
+// the bottom row, so neither can be forest
+pub fn parse_input(input: &str) -> Result<Grid<Tile>, ParseError> {
+    let grid: Grid<Tile> = Grid::parse(input)?;
+    if grid.num_columns() < 2 {
+        return Err(ParseError::message("expected a map at least 2 tiles wide"));
+    }
+
+    let ends = [
+        ((0, 1), "expected the start of the hike"),
+        (
+            (grid.num_rows() - 1, grid.num_columns() - 2),
+            "expected the end of the hike",
+        ),
+    ];
+    for ((row, col), reason) in ends {
+        if matches!(grid[(row, col)], Tile::Forest) {
+            return Err(ParseError::at(row + 1, col + 1, "#", reason));
+        }
+    }
+
+    Ok(grid)
+}
+
+pub trait GridExt {
+    fn replace_slopes_with_paths(&mut self);
+    fn get_successors(&self, position: Position) -> Vec<(Position, Direction)>;
+    fn to_graph(&self) -> Graph;
+    fn longest_path_len(&self, cancel: &CancellationToken) -> Result<usize>;
+}
+
+impl GridExt for Grid<Tile> {
+    fn replace_slopes_with_paths(&mut self) {
+        for elem in self.iter_mut() {
+            if matches!(*elem, Tile::Slope(_)) {
+                *elem = Tile::Path;
+            }
+        }
+    }
+
+    fn get_successors(&self, position: Position) -> Vec<(Position, Direction)> {
+        let try_direction = |direction_to_try: Direction| {
+            let new_position = self.try_move(position, direction_to_try)?;
+
+            if matches!(self[new_position], Tile::Forest) {
+                None
+            } else {
+                Some((new_position, direction_to_try))
+            }
+        };
+
+        match self[position] {
+            Tile::Path => Direction::iter().filter_map(try_direction).collect(),
+            Tile::Slope(direction_to_try) => {
+                if let Some(succ) = try_direction(direction_to_try) {
+                    vec![succ]
+                } else {
+                    vec![]
+                }
+            }
+            Tile::Forest => {
+                panic!(
+                    "Cannot get the successors of a Forest. get_successors called on ({:?})",
+                    position
+                )
+            }
+        }
+    }
+
+    fn to_graph(&self) -> Graph {
+        // assumes paths on the grid have width 1
+        let mut stack: Vec<Position> = vec![(0, 1)];
+        let mut done = HashSet::new();
+        let mut graph = Graph::new();
+
+        while let Some(cur_vertex) = stack.pop() {
+            if !done.insert(cur_vertex) {
+                continue;
+            }
+
+            // HashMap<(successor (vertex): Position, distance: usize)>
+            let mut cur_vertex_successors: HashMap<(usize, usize), usize> = HashMap::new();
+
+            for (position, direction) in self.get_successors(cur_vertex) {
+                let Some(trail) = follow_trail(self, position, direction) else {
+                    continue;
+                };
+
+                // the end of the trail is a new vertex, with an edge from cur_vertex that has
+                // weight distance
+                let vertex = *trail.last().unwrap();
+                let distance = trail.len();
+                if !done.contains(&vertex) {
+                    stack.push(vertex);
+                }
+
+                cur_vertex_successors
+                    .entry(vertex)
+                    .and_modify(|prev_dist| *prev_dist = (*prev_dist).max(distance))
+                    .or_insert(distance);
+            }
+
+            let from = graph.intern(cur_vertex);
+            for (successor, distance) in cur_vertex_successors {
+                let to = graph.intern(successor);
+                graph.add_edge(from, to, distance);
+            }
+        }
+
+        graph
+    }
+
+    fn longest_path_len(&self, cancel: &CancellationToken) -> Result<usize> {
+        let graph = self.to_graph();
+        let (source, target) = end_nodes(self, &graph)?;
+
+        let (len, _) = longest_path_brute_force(&graph, source, target, cancel)?
+            .context("there is no path from the start to the end")?;
+        Ok(len)
+    }
+}
+
+// the tiles from position, just stepped onto in direction, up to and including the next vertex.
+// None if the trail is a dead end
+fn follow_trail(
+    grid: &Grid<Tile>,
+    mut cur_pos: Position,
+    mut prev_direction: Direction,
+) -> Option<Vec<Position>> {
+    let mut trail = vec![cur_pos];
+
+    loop {
+        let cur_pos_successors = grid.get_successors(cur_pos);
+
+        if cur_pos.0 == 0 || cur_pos.0 == grid.num_rows() - 1 || cur_pos_successors.len() >= 3 {
+            return Some(trail);
+        }
+
+        // since not a vertex, must have 0 or 1 or 2 successors
+        // and 1 of them may be where you just came from, so
+        // need to either get the 1 valid new successor
+        // or end this since this path doesn't lead to a new vertex
+        (cur_pos, prev_direction) = cur_pos_successors
+            .into_iter()
+            .find(|&(_, direction)| !direction.is_opposite(prev_direction))?;
+
+        trail.push(cur_pos);
+    }
+}
+
+// the ids of the start and the end of the trail in the graph of grid. the graph is built from
+// the start, so the end is only in it if there's a trail there
+fn end_nodes(grid: &Grid<Tile>, graph: &Graph) -> Result<(NodeId, NodeId)> {
+    let source = graph.id(&(0, 1)).unwrap();
+    let target = graph
+        .id(&(grid.num_rows() - 1, grid.num_columns() - 2))
+        .context("there is no path from the start to the end")?;
+
+    Ok((source, target))
+}
+
+fn _longest_path_len_no_cycles(grid: &Grid<Tile>) -> usize {
+    let graph = grid.to_graph();
+    let (source, target) = end_nodes(grid, &graph).unwrap();
+
+    _longest_path_len(&graph, source, target).unwrap()
+}
+
+// the junctions of the trails, weighted by the number of steps between them
+pub type Graph = graph::Graph<Position>;
+
+/// Find the longest path length by relaxing edges in topological order
+///
+/// Only works if the graph has no cycles
+fn _longest_path_len(graph: &Graph, source: NodeId, target: NodeId) -> Option<usize> {
+    let mut distances = vec![None; graph.len()];
+    distances[source as usize] = Some(0);
+
+    for from in graph.topological_sort()? {
+        if let Some(distance_from) = distances[from as usize] {
+            for &(to, weight) in graph.successors(from) {
+                let distance_to = &mut distances[to as usize];
+                *distance_to = (*distance_to).max(Some(distance_from + weight));
+            }
+        }
+    }
+
+    distances[target as usize]
+}
+
+// brute force longest path from s to t, and the nodes along it
+// without going through a node in open_set. the number of paths grows exponentially with the
+// size of the graph, so this checks cancel as it goes
+fn longest_path_brute_force(
+    graph: &Graph,
+    source: NodeId,
+    target: NodeId,
+    cancel: &CancellationToken,
+) -> Result<Option<(usize, Vec<NodeId>)>, Cancelled> {
+    enum StackItem {
+        Todo((NodeId, usize)),
+        Done(NodeId),
+    }
+
+    let mut open_set = vec![false; graph.len()];
+    // the nodes in open_set, in order
+    let mut path = Vec::new();
+    let mut stack = vec![StackItem::Todo((source, 0))];
+
+    let mut result: Option<(usize, Vec<NodeId>)> = None;
+    let mut num_steps = 0;
+
+    while let Some(item) = stack.pop() {
+        cancel.tick(num_steps)?;
+        num_steps += 1;
+
+        match item {
+            StackItem::Todo((node, distance)) => {
+                if node == target {
+                    if result
+                        .as_ref()
+                        .is_none_or(|&(longest, _)| distance > longest)
+                    {
+                        let mut longest_path = path.clone();
+                        longest_path.push(target);
+                        result = Some((distance, longest_path));
+                    }
+                    continue;
+                }
+
+                open_set[node as usize] = true;
+                path.push(node);
+
+                // removes from open_set when pops
+                stack.push(StackItem::Done(node));
+
+                for &(successor, weight) in graph.successors(node) {
+                    if !open_set[successor as usize] {
+                        stack.push(StackItem::Todo((successor, distance + weight)));
+                    }
+                }
+            }
+            StackItem::Done(node) => {
+                open_set[node as usize] = false;
+                path.pop();
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+// the forest in green, the trails in tan with darker slopes, and the longest hike in white
+pub fn render(grid: &Grid<Tile>) -> Result<Image> {
+    let graph = grid.to_graph();
+    let (source, target) = end_nodes(grid, &graph)?;
+    let (_, path) = longest_path_brute_force(&graph, source, target, &CancellationToken::new())?
+        .context("there is no path from the start to the end")?;
+
+    let mut image = grid.map(|tile| match tile {
+        Tile::Forest => [20, 70, 30],
+        Tile::Path => [200, 170, 120],
+        Tile::Slope(_) => [150, 110, 70],
+    });
+
+    image[*graph.name(source)] = image::WHITE;
+    for edge in path.windows(2) {
+        let (from, to) = (*graph.name(edge[0]), *graph.name(edge[1]));
+        let (_, distance) = graph
+            .successors(edge[0])
+            .iter()
+            .find(|&&(successor, _)| successor == edge[1])
+            .unwrap();
+
+        // the trail the edge was made from
+        let trail = grid
+            .get_successors(from)
+            .into_iter()
+            .filter_map(|(position, direction)| follow_trail(grid, position, direction))
+            .find(|trail| trail.last() == Some(&to) && trail.len() == *distance)
+            .unwrap();
+
+        for position in trail {
+            image[position] = image::WHITE;
+        }
+    }
+
+    Ok(image)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'a> = Grid<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Result<usize> {
+        Self::part1_cancellable(grid, &CancellationToken::new())
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Result<usize> {
+        Self::part2_cancellable(grid, &CancellationToken::new())
+    }
+
+    fn part1_cancellable(grid: &Self::Parsed<'_>, cancel: &CancellationToken) -> Result<usize> {
+        grid.longest_path_len(cancel)
+    }
+
+    fn part2_cancellable(grid: &Self::Parsed<'_>, cancel: &CancellationToken) -> Result<usize> {
+        let mut grid = grid.clone();
+        grid.replace_slopes_with_paths();
+        grid.longest_path_len(cancel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const TEST_INPUT: &str = "#.#####################\n\
+                              #.......#########...###\n\
+                              #######.#########.#.###\n\
+                              ###.....#.>.>.###.#.###\n\
+                              ###v#####.#v#.###.#.###\n\
+                              ###.>...#.#.#.....#...#\n\
+                              ###v###.#.#.#########.#\n\
+                              ###...#.#.#.......#...#\n\
+                              #####.#.#.#######.#.###\n\
+                              #.....#.#.#.......#...#\n\
+                              #.#####.#.#.#########v#\n\
+                              #.#...#...#...###...>.#\n\
+                              #.#.#v#######v###.###v#\n\
+                              #...#.>.#...>.>.#.###.#\n\
+                              #####v#.#.###v#.#.###.#\n\
+                              #.....#...#...#.#.#...#\n\
+                              #.#########.###.#.#.###\n\
+                              #...###...#...#...#.###\n\
+                              ###.###.#.###v#####v###\n\
+                              #...#...#.#.>.>.#.>.###\n\
+                              #.###.###.#.###.#.#v###\n\
+                              #.....###...###...#...#\n\
+                              #####################.#";
+
+    const MY_TEST_INPUT: &str = "#.#####\n\
+                                 #.....#\n\
+                                 ###.#.#\n\
+                                 #.....#\n\
+                                 #.###.#\n\
+                                 #.....#\n\
+                                 #####.#";
+
+    #[test]
+    fn test_part1() {
+        let grid = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            grid.longest_path_len(&CancellationToken::new()).unwrap(),
+            94
+        );
+    }
+
+    #[test]
+    fn test_part1_no_brute_force() {
+        let grid = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(_longest_path_len_no_cycles(&grid), 94);
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut grid = parse_input(TEST_INPUT).unwrap();
+
+        grid.replace_slopes_with_paths();
+
+        assert_eq!(
+            grid.longest_path_len(&CancellationToken::new()).unwrap(),
+            154
+        );
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let err = grid.longest_path_len(&cancel).unwrap_err();
+        assert_eq!(err.to_string(), "cancelled after 0 steps");
+    }
+
+    #[test]
+    fn test_bad_maps() {
+        let parse = |input| parse_input(input).map(|_| ());
+
+        assert_eq!(
+            parse("#\n"),
+            Err(ParseError::message("expected a map at least 2 tiles wide"))
+        );
+        assert_eq!(
+            parse("##.\n#.#"),
+            Err(ParseError::at(1, 2, "#", "expected the start of the hike"))
+        );
+        assert_eq!(
+            parse("#.#\n###"),
+            Err(ParseError::at(2, 2, "#", "expected the end of the hike"))
+        );
+
+        // both ends are there, but the forest is in between
+        let grid = parse_input("#.#\n###\n#.#").unwrap();
+        let err = grid
+            .longest_path_len(&CancellationToken::new())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "there is no path from the start to the end"
+        );
+    }
+
+    #[test]
+    fn my_test() {
+        let grid = parse_input(MY_TEST_INPUT).unwrap();
+
+        let graph = grid.to_graph();
+
+        for (from, to, weight) in graph.edges() {
+            println!("{:?} -> {:?}: {weight}", graph.name(from), graph.name(to));
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+use serde::Serialize;
+use std::fmt::Write;
+
+#[derive(Debug, Serialize)]
+struct Vector3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Vector3 {
+    const fn filled_with(val: f64) -> Self {
+        Vector3 {
+            x: val,
+            y: val,
+            z: val,
+        }
+    }
+}
+
+impl TryFrom<&str> for Vector3 {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, ParseError> {
+        // ex: "19, 13, 30"
+        let vals = value
+            .split(',')
+            .map(|s| parse_number(s.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let [x, y, z] = vals[..] else {
+            return Err(ParseError::new(
+                value,
+                "expected 3 numbers like \"19, 13, 30\"",
+            ));
+        };
+        Ok(Vector3 { x, y, z })
+    }
+}
+
+impl From<(f64, f64)> for Vector3 {
+    fn from(value: (f64, f64)) -> Self {
+        Vector3 {
+            x: value.0,
+            y: value.1,
+            z: 0.,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Hailstone {
+    position: Vector3,
+    velocity: Vector3,
+}
+
+pub struct Bounds {
+    min: Vector3,
+    max: Vector3,
+}
+
+impl Bounds {
+    fn contains_point_2d(&self, point: &Vector3) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+    }
+}
+
+pub const PART1_BOUNDS: Bounds = Bounds {
+    min: Vector3::filled_with(200000000000000.),
+    max: Vector3::filled_with(400000000000000.),
+};
+
+enum LineIntersection {
+    SinglePoint(Vector3),
+    #[allow(dead_code)]
+    InfinitePoints,
+}
+
+impl Hailstone {
+    fn intersection_point_2d(&self, other: &Self) -> Option<LineIntersection> {
+        // TODO: assumed no undefined slopes, based on my input
+
+        let slope1 = self.velocity.y / self.velocity.x;
+        let slope2 = other.velocity.y / other.velocity.x;
+
+        // TODO: also assume parallel lines don't intersect, :)
+        if slope1 == slope2 {
+            return None;
+        }
+
+        let intersection_x = (-self.position.y + other.position.y + slope1 * self.position.x
+            - slope2 * other.position.x)
+            / (slope1 - slope2);
+
+        let intersection_y = slope1 * (intersection_x - self.position.x) + self.position.y;
+
+        Some(LineIntersection::SinglePoint(
+            (intersection_x, intersection_y).into(),
+        ))
+    }
+
+    fn crosses_in_future_2d(&self, point: &Vector3) -> bool {
+        (point.x - self.position.x).signum() == self.velocity.x.signum()
+            && (point.y - self.position.y).signum() == self.velocity.y.signum()
+    }
+
+    fn intersects_in_bounds_2d(&self, other: &Self, bounds: &Bounds) -> bool {
+        match self.intersection_point_2d(other) {
+            Some(intersection) => match intersection {
+                LineIntersection::SinglePoint(point) => {
+                    bounds.contains_point_2d(&point)
+                        && self.crosses_in_future_2d(&point)
+                        && other.crosses_in_future_2d(&point)
+                }
+                LineIntersection::InfinitePoints => todo!(),
+            },
+            None => false,
+        }
+    }
+}
+
+impl TryFrom<&str> for Hailstone {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, ParseError> {
+        // ex: "19, 13, 30 @ -2,  1, -2"
+        let (position_str, velocity_str) = value.split_once('@').ok_or_else(|| {
+            ParseError::new(
+                value,
+                "expected a hailstone like \"19, 13, 30 @ -2, 1, -2\"",
+            )
+        })?;
+
+        Ok(Hailstone {
+            position: position_str.try_into()?,
+            velocity: velocity_str.try_into()?,
+        })
+    }
+}
+
+// part 2 needs 3 hailstones to write its equations for
+pub fn parse_input(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    let hailstones = input
+        .lines()
+        .map(Hailstone::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if hailstones.len() < 3 {
+        return Err(ParseError::message("expected at least 3 hailstones"));
+    }
+
+    Ok(hailstones)
+}
+
+pub fn part1(hailstones: &[Hailstone], bounds: &Bounds) -> usize {
+    let mut result = 0;
+    for (i, stone1) in hailstones.iter().enumerate() {
+        for stone2 in &hailstones[i + 1..] {
+            if stone1.intersects_in_bounds_2d(stone2, bounds) {
+                result += 1;
+            }
+        }
+    }
+
+    result
+}
+
+// returns the equations to put into part2.py
+pub fn part2(hailstones: &[Hailstone]) -> String {
+    // find 3 lines, assume a path through these lines
+    // should pass through all lines because i think there's only 1 unique solution
+
+    let mut equations = String::new();
+
+    for (i, hailstone) in hailstones[0..3].iter().enumerate() {
+        writeln!(
+            equations,
+            "x+{time}*u{:+}{:+}*{time},\n\
+             y+{time}*v{:+}{:+}*{time},\n\
+             z+{time}*w{:+}{:+}*{time},\n",
+            -hailstone.position.x,
+            -hailstone.velocity.x,
+            -hailstone.position.y,
+            -hailstone.velocity.y,
+            -hailstone.position.z,
+            -hailstone.velocity.z,
+            time = (b'r' + i as u8) as char
+        )
+        .unwrap();
+    }
+
+    equations
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed<'a> = Vec<Hailstone>;
+    type Answer1 = usize;
+    // part 2 has no numeric answer, it is the system of equations to hand to a solver
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(hailstones: &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part1(hailstones, &PART1_BOUNDS))
+    }
+
+    fn part2(hailstones: &Self::Parsed<'_>) -> Result<String> {
+        Ok(part2(hailstones).trim_end().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, part1, part2, Bounds, Vector3};
+    use common::ParseError;
+
+    const TEST_INPUT: &str = "19, 13, 30 @ -2, 1, -2\n\
+                              18, 19, 22 @ -1, -1, -2\n\
+                              20, 25, 34 @ -2, -2, -4\n\
+                              12, 31, 28 @ -1, -2, -1\n\
+                              20, 19, 15 @ 1, -5, -3";
+
+    const TEST_BOUNDS: Bounds = Bounds {
+        min: Vector3::filled_with(7.),
+        max: Vector3::filled_with(27.),
+    };
+
+    #[test]
+    fn test_part1() {
+        let hailstones = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(part1(&hailstones, &TEST_BOUNDS), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let hailstones = parse_input(TEST_INPUT).unwrap();
+        part2(&hailstones);
+
+        let two_hailstones = TEST_INPUT.lines().take(2).collect::<Vec<_>>().join("\n");
+        assert_eq!(
+            parse_input(&two_hailstones).err(),
+            Some(ParseError::message("expected at least 3 hailstones"))
+        );
+    }
+}
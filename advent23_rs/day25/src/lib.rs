@@ -0,0 +1,230 @@
+use anyhow::{bail, Result};
+use common::{ParseError, Solution};
+use graph::NodeId;
+use priority_queue::PriorityQueue;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+// the wires go both ways, so there's an edge each way between connected components
+pub type Graph<'a> = graph::Graph<&'a str>;
+
+pub fn parse_input(input: &str) -> Result<Graph<'_>, ParseError> {
+    let mut result = Graph::new();
+
+    for line in input.lines() {
+        let (node, successors) = line
+            .split_once(':')
+            .filter(|(node, _)| !node.is_empty())
+            .ok_or_else(|| ParseError::new(line, "expected a line like \"jqt: rhn xhk nvd\""))?;
+
+        let node = result.intern(node);
+        for successor in successors.split_ascii_whitespace() {
+            let successor = result.intern(successor);
+            result.add_edge(node, successor, 1);
+            result.add_edge(successor, node, 1);
+        }
+    }
+
+    if result.len() < 2 {
+        return Err(ParseError::message("expected at least 2 components"));
+    }
+
+    Ok(result)
+}
+
+pub struct MinCutInfo<'a> {
+    pub min_cut_size: usize,
+    pub partition_a: Vec<&'a str>,
+    pub partition_b: Vec<&'a str>,
+}
+
+// this documentation helped:
+// https://networkx.org/documentation/stable/_modules/networkx/algorithms/connectivity/stoerwagner.html#stoer_wagner
+// assumes graph is connected and has at least 2 nodes
+pub fn stoer_wagner<'a>(graph: &Graph<'a>) -> MinCutInfo<'a> {
+    let mut weighted_graph: HashMap<NodeId, RefCell<HashMap<NodeId, usize>>> = graph
+        .nodes()
+        .map(|node| {
+            (
+                node,
+                graph
+                    .successors(node)
+                    .iter()
+                    .map(|&(succ, _)| (succ, 1))
+                    .collect::<HashMap<_, _>>()
+                    .into(),
+            )
+        })
+        .collect();
+
+    let mut min_cut_size = usize::MAX;
+    let mut contractions = Vec::new();
+    let mut best_phase = 0;
+
+    for i in 0.. {
+        // get an arbitrary element of the graph
+        let (&u, u_successors) = weighted_graph.iter().next().unwrap();
+        let mut partition_a = HashSet::from([u]);
+
+        let mut u = u;
+
+        let mut heap: PriorityQueue<NodeId, usize> = u_successors
+            .borrow()
+            .iter()
+            .map(|(&succ, &weight)| (succ, weight))
+            .collect();
+
+        for _ in 0..graph.len() - i - 2 {
+            (u, _) = heap.pop().unwrap();
+
+            partition_a.insert(u);
+            for (&successor, &weight) in weighted_graph[&u].borrow().iter() {
+                if !partition_a.contains(&successor)
+                    && !heap.change_priority_by(&successor, |p| *p += weight)
+                {
+                    heap.push(successor, weight);
+                }
+            }
+        }
+
+        let u = u;
+        let (v, w) = heap.pop().unwrap();
+
+        if w < min_cut_size {
+            min_cut_size = w;
+            best_phase = i;
+        }
+
+        contractions.push((u, v));
+
+        if i == graph.len() - 2 {
+            break;
+        }
+        // contract u and v into 1 node
+        let mut combined_succs = weighted_graph[&u].borrow_mut();
+        combined_succs.remove(&v);
+        for (&successor, &weight) in weighted_graph[&v].borrow().iter() {
+            if successor == u {
+                continue;
+            }
+
+            let combined_weight = combined_succs.entry(successor).or_default();
+
+            *combined_weight += weight;
+
+            let mut succ_succs = weighted_graph[&successor].borrow_mut();
+            succ_succs.insert(u, *combined_weight);
+            succ_succs.remove(&v);
+        }
+        drop(combined_succs);
+
+        weighted_graph.remove(&v);
+    }
+
+    // nodes are named by their ids in graph
+    let mut contractions_graph = graph::Graph::new();
+    for &(u, v) in &contractions[0..best_phase] {
+        let (u, v) = (contractions_graph.intern(u), contractions_graph.intern(v));
+        contractions_graph.add_edge(u, v, 1);
+        contractions_graph.add_edge(v, u, 1);
+    }
+
+    // source may not have been contracted with anything, then it's on its own
+    let source = contractions_graph.intern(contractions[best_phase].1);
+
+    let partition_a = contractions_graph
+        .dfs(source)
+        .into_iter()
+        .map(|node| *contractions_graph.name(node))
+        .collect::<HashSet<_>>();
+    let partition_b = graph
+        .nodes()
+        .filter(|node| !partition_a.contains(node))
+        .map(|node| *graph.name(node))
+        .collect();
+
+    MinCutInfo {
+        min_cut_size,
+        partition_a: partition_a
+            .into_iter()
+            .map(|node| *graph.name(node))
+            .collect(),
+        partition_b,
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed<'a> = Graph<'a>;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    const HAS_PART2: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(graph: &Self::Parsed<'_>) -> Result<usize> {
+        let MinCutInfo {
+            min_cut_size,
+            partition_a,
+            partition_b,
+        } = stoer_wagner(graph);
+
+        if min_cut_size != 3 {
+            bail!("Expected a minimum cut of 3 wires, found {min_cut_size}");
+        }
+
+        Ok(partition_a.len() * partition_b.len())
+    }
+
+    fn part2(_: &Self::Parsed<'_>) -> Result<&'static str> {
+        bail!("Day 25 only has one part")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "jqt: rhn xhk nvd\n\
+                              rsh: frs pzl lsr\n\
+                              xhk: hfx\n\
+                              cmg: qnr nvd lhk bvb\n\
+                              rhn: xhk bvb hfx\n\
+                              bvb: xhk hfx\n\
+                              pzl: lsr hfx nvd\n\
+                              qnr: nvd\n\
+                              ntq: jqt hfx bvb xhk\n\
+                              nvd: lhk\n\
+                              lsr: lhk\n\
+                              rzs: qnr cmg lsr rsh\n\
+                              frs: qnr lhk lsr";
+
+    #[test]
+    fn test_parsing() {
+        let graph = parse_input(TEST_INPUT).unwrap();
+        for node in graph.nodes() {
+            let successors = graph.successors(node).iter();
+            let successors = successors.map(|&(successor, _)| *graph.name(successor));
+            println!("{}: {:?}", graph.name(node), successors.collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let graph = parse_input(TEST_INPUT).unwrap();
+        let MinCutInfo {
+            min_cut_size,
+            partition_a,
+            partition_b,
+        } = stoer_wagner(&graph);
+
+        assert_eq!(min_cut_size, 3);
+        assert_eq!(partition_a.len() * partition_b.len(), 54);
+    }
+}
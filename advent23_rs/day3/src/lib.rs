@@ -0,0 +1,151 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref INT_REGEX: Regex = Regex::new("[0-9]+").unwrap();
+}
+
+#[derive(Debug, Serialize)]
+pub struct EngineNumber {
+    row_index: usize,
+    start: usize,
+    end: usize,
+    number: i32,
+}
+
+fn is_symbol(c: u8) -> bool {
+    !c.is_ascii_digit() && c != b'.'
+}
+
+impl EngineNumber {
+    fn get_neighbors(
+        &self,
+        num_rows: usize,
+        num_cols: usize,
+    ) -> impl '_ + Iterator<Item = (usize, usize)> {
+        let start_row = if self.row_index > 0 {
+            self.row_index - 1
+        } else {
+            0
+        };
+
+        let start_col = if self.start > 0 { self.start - 1 } else { 0 };
+
+        // lmao I could have just returned a vector instead but I'm just known to be silly like that
+        (start_row..=self.row_index + 1)
+            .filter(move |&i| i < num_rows)
+            .flat_map(move |i| {
+                (start_col..=self.end)
+                    .filter(move |&j| j < num_cols)
+                    .map(move |j| (i, j))
+            })
+            .filter(|&(i, j)| i != self.row_index || j < self.start || j == self.end)
+    }
+
+    fn is_part_number(&self, puzzle_input: &[&[u8]]) -> bool {
+        let num_rows = puzzle_input.len();
+        let num_cols = puzzle_input[0].len();
+
+        return self
+            .get_neighbors(num_rows, num_cols)
+            .any(|(i, j)| is_symbol(puzzle_input[i][j]));
+    }
+}
+
+pub fn find_engine_numbers(puzzle_input: &[&str]) -> Result<Vec<EngineNumber>, ParseError> {
+    let mut result = Vec::new();
+
+    for (i, &line) in puzzle_input.iter().enumerate() {
+        for num_match in INT_REGEX.find_iter(line) {
+            result.push(EngineNumber {
+                row_index: i,
+                start: num_match.start(),
+                end: num_match.end(),
+                number: parse_number(num_match.as_str())?,
+            })
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn part1(puzzle_input: &[&[u8]], engine_numbers: &[EngineNumber]) -> i32 {
+    engine_numbers
+        .iter()
+        .filter_map(|engine_number| {
+            if engine_number.is_part_number(puzzle_input) {
+                Some(engine_number.number)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub fn part2(puzzle_input: &[&[u8]], engine_numbers: &[EngineNumber]) -> i32 {
+    let num_rows = puzzle_input.len();
+    let num_cols = puzzle_input[0].len();
+
+    let mut map = HashMap::new();
+    for engine_number in engine_numbers {
+        for (i, j) in engine_number.get_neighbors(num_rows, num_cols) {
+            if puzzle_input[i][j] == b'*' {
+                map.entry((i, j))
+                    .and_modify(|val| {
+                        *val *= if *val < 0 {
+                            -engine_number.number
+                        } else {
+                            engine_number.number
+                        };
+                    })
+                    .or_insert(-engine_number.number);
+            }
+        }
+    }
+
+    map.values().filter(|&&x| x >= 0).sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    // the schematic lines, and the numbers found in them
+    type Parsed<'a> = (Vec<&'a [u8]>, Vec<EngineNumber>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let Some(first) = lines.first() else {
+            return Err(ParseError::message(
+                "expected a schematic, the input is empty",
+            ));
+        };
+        // the neighbors of a number are looked up by column in every row
+        if let Some(line) = lines.iter().find(|line| line.len() != first.len()) {
+            return Err(ParseError::new(
+                line,
+                format!("expected a row of length {}", first.len()),
+            ));
+        }
+
+        let engine_numbers = find_engine_numbers(&lines)?;
+
+        Ok((
+            lines.into_iter().map(str::as_bytes).collect(),
+            engine_numbers,
+        ))
+    }
+
+    fn part1((puzzle_input, engine_numbers): &Self::Parsed<'_>) -> Result<i32> {
+        Ok(part1(puzzle_input, engine_numbers))
+    }
+
+    fn part2((puzzle_input, engine_numbers): &Self::Parsed<'_>) -> Result<i32> {
+        Ok(part2(puzzle_input, engine_numbers))
+    }
+}
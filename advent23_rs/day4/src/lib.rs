@@ -0,0 +1,82 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+
+// the number of winning numbers you have on a card
+fn get_num_of_winning_numbers(line: &str) -> Result<usize, ParseError> {
+    let (_, all_nums) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(line, "expected a line like \"Card 1: 41 48 | 83 86\""))?;
+
+    let (winning_nums_str, your_nums_str) = all_nums
+        .split_once('|')
+        .ok_or_else(|| ParseError::new(all_nums, "expected a '|' between the two lists"))?;
+
+    let mut set = [false; 100];
+
+    for num_str in winning_nums_str.split_ascii_whitespace() {
+        let num: usize = parse_number(num_str)?;
+        if num >= set.len() {
+            return Err(ParseError::new(num_str, "expected a number below 100"));
+        }
+        set[num] = true;
+    }
+
+    let mut result = 0;
+    for num_str in your_nums_str.split_ascii_whitespace() {
+        let num: usize = parse_number(num_str)?;
+        if set.get(num) == Some(&true) {
+            result += 1;
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    input.lines().map(get_num_of_winning_numbers).collect()
+}
+
+fn score_card_part1(result: usize) -> i32 {
+    if result == 0 {
+        0
+    } else {
+        1 << (result - 1)
+    }
+}
+
+pub fn part1(cards: &[usize]) -> i32 {
+    cards.iter().map(|&result| score_card_part1(result)).sum()
+}
+
+pub fn part2(cards: &[usize]) -> i32 {
+    let mut card_amts = vec![1; cards.len()];
+
+    for (i, &result) in cards.iter().enumerate() {
+        for j in 1..=result {
+            card_amts[i + j] += card_amts[i];
+        }
+    }
+
+    card_amts.iter().sum()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    // the number of winning numbers on each card
+    type Parsed<'a> = Vec<usize>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(cards: &Self::Parsed<'_>) -> Result<i32> {
+        Ok(part1(cards))
+    }
+
+    fn part2(cards: &Self::Parsed<'_>) -> Result<i32> {
+        Ok(part2(cards))
+    }
+}
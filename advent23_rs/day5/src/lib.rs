@@ -0,0 +1,192 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+use interval::IntervalSet;
+use serde::Serialize;
+use std::ops::Range;
+
+#[derive(Debug, Serialize)]
+pub struct MapRange {
+    range: Range<usize>,
+    dest_start: usize,
+}
+
+impl MapRange {
+    fn new(start: usize, end: usize, dest_start: usize) -> Self {
+        MapRange {
+            range: start..end,
+            dest_start,
+        }
+    }
+
+    fn src_to_dest(&self, val: usize) -> usize {
+        val - self.range.start + self.dest_start
+    }
+
+    fn src_to_dest_range(&self, range: Range<usize>) -> Range<usize> {
+        self.src_to_dest(range.start)..self.src_to_dest(range.end)
+    }
+}
+
+pub fn parse_input(puzzle_input: &str) -> Result<(Vec<usize>, Vec<Vec<MapRange>>), ParseError> {
+    let mut puzzle_lines = puzzle_input.lines();
+
+    // split first line on : then parse usizes as seed values
+    let seeds_line = puzzle_lines.next().unwrap_or_default();
+    let seeds_str = seeds_line
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(seeds_line, "expected a line like \"seeds: 79 14\""))?
+        .1;
+    let seed_strs = seeds_str.split_ascii_whitespace().collect::<Vec<_>>();
+    let seeds = seed_strs
+        .iter()
+        .map(|seed_str| parse_number(seed_str))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    // part 2 reads the seeds as pairs of a start and a length, and both parts want a lowest location
+    if seeds.is_empty() || seeds.len() % 2 != 0 {
+        return Err(ParseError::missing_after(
+            seeds_line,
+            "expected pairs of a seed start and a number of seeds",
+        ));
+    }
+    if let Some(i) = (1..seeds.len()).step_by(2).find(|&i| seeds[i] == 0) {
+        return Err(ParseError::new(
+            seed_strs[i],
+            "expected a number of seeds of at least 1",
+        ));
+    }
+
+    let mut maps: Vec<Vec<MapRange>> = Vec::new();
+
+    let mut new_map_next = false;
+    for line in puzzle_lines {
+        if line.is_empty() {
+            new_map_next = true;
+        } else if new_map_next {
+            // line is: x-to-y map:
+            maps.push(Vec::new());
+            new_map_next = false;
+        } else {
+            // line is: [dest start] [source start] [range len]
+            let nums = line
+                .split_ascii_whitespace()
+                .map(parse_number)
+                .collect::<Result<Vec<usize>, _>>()?;
+
+            let [dest_start, src_start, len] = nums[..] else {
+                return Err(ParseError::new(
+                    line,
+                    "expected a destination start, source start and range length",
+                ));
+            };
+
+            maps.last_mut()
+                .ok_or_else(|| ParseError::new(line, "expected a map name like \"x-to-y map:\""))?
+                .push(MapRange::new(src_start, src_start + len, dest_start))
+        }
+    }
+
+    // sort by start of source range
+    for map in maps.iter_mut() {
+        map.sort_by_key(|mr| mr.range.start);
+    }
+
+    Ok((seeds, maps))
+}
+
+trait Map {
+    fn follow(&self, seed: usize) -> usize;
+
+    fn follow_ranges(&self, src_ranges: &IntervalSet<usize>) -> IntervalSet<usize>;
+}
+
+// assumes the map is in sorted order!
+impl Map for [MapRange] {
+    fn follow(&self, src_val: usize) -> usize {
+        // TODO: use binary search to find the range containing src_val
+        // (there can be at most 1)
+        // for now just use a linear scan
+        for map_range in self.iter() {
+            if map_range.range.contains(&src_val) {
+                return map_range.src_to_dest(src_val);
+            }
+        }
+        src_val
+    }
+
+    fn follow_ranges(&self, src_ranges: &IntervalSet<usize>) -> IntervalSet<usize> {
+        let mut result = IntervalSet::new();
+        let mut unmapped = src_ranges.clone();
+
+        for map_range in self.iter() {
+            let (mapped, rest) = unmapped.split(&map_range.range.clone().into());
+            for range in mapped.ranges() {
+                result.insert(map_range.src_to_dest_range(range.clone()));
+            }
+            unmapped = rest;
+        }
+
+        // anything not in a map range keeps its value
+        result.union(&unmapped)
+    }
+}
+
+trait MapSequence {
+    fn follow(&self, seed: usize) -> usize;
+    fn follow_ranges(&self, seed_ranges: IntervalSet<usize>) -> IntervalSet<usize>;
+}
+
+impl MapSequence for [Vec<MapRange>] {
+    fn follow(&self, seed: usize) -> usize {
+        let mut src_val = seed;
+        // for each map
+        for map in self.iter() {
+            src_val = map.follow(src_val);
+        }
+
+        src_val
+    }
+
+    fn follow_ranges(&self, seed_ranges: IntervalSet<usize>) -> IntervalSet<usize> {
+        let mut src_ranges = seed_ranges;
+        for map in self.iter() {
+            src_ranges = map.follow_ranges(&src_ranges);
+        }
+
+        src_ranges
+    }
+}
+
+pub fn part1(seeds: &[usize], maps: &[Vec<MapRange>]) -> usize {
+    seeds.iter().map(|&seed| maps.follow(seed)).min().unwrap()
+}
+
+// remember assume map ranges are sorted (each maps[i] is sorted)
+pub fn part2(seeds: &[usize], maps: &[Vec<MapRange>]) -> usize {
+    let seed_ranges = seeds
+        .chunks_exact(2)
+        .map(|chunk| chunk[0]..chunk[0] + chunk[1])
+        .collect();
+
+    maps.follow_ranges(seed_ranges).min().unwrap()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = (Vec<usize>, Vec<Vec<MapRange>>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((seeds, maps): &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part1(seeds, maps))
+    }
+
+    fn part2((seeds, maps): &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part2(seeds, maps))
+    }
+}
@@ -0,0 +1,158 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+use serde::Serialize;
+use std::ops::RangeInclusive;
+
+#[derive(Serialize)]
+pub struct Race {
+    time: usize,
+    record: usize,
+}
+
+impl Race {
+    // need to solve (hold time) * ((time limit) - (hold time)) > record
+    // in other words, -x^2 + (time limit) * x - record > 0
+    // assume f64 does not cause an error, since numbers are a good size
+    fn compute_elite_hold_times(&self) -> Option<RangeInclusive<usize>> {
+        // solves -x^2 + time_limit * x - record > 0
+        let time_f = self.time as f64;
+
+        let determinant = time_f * time_f - 4.0 * self.record as f64;
+        if determinant < 0.0 {
+            return None;
+        }
+        let sqrt_determinant = determinant.sqrt();
+        let xmin_f = (time_f - sqrt_determinant) / 2.0;
+        let xmax_f = (time_f + sqrt_determinant) / 2.0;
+
+        let mut xmin = xmin_f.ceil() as usize;
+        let mut xmax = xmax_f.floor() as usize;
+
+        if xmin * (self.time - xmin) <= self.record {
+            xmin += 1;
+        }
+
+        if xmax * (self.time - xmax) <= self.record {
+            xmax -= 1;
+        }
+
+        if xmin <= xmax {
+            Some(xmin..=xmax)
+        } else {
+            None
+        }
+    }
+
+    fn num_elite_hold_times(&self) -> usize {
+        match self.compute_elite_hold_times() {
+            Some(range) => range.count(),
+            None => 0,
+        }
+    }
+}
+
+// the numbers after the label on a line like "Time:      7  15   30"
+fn parse_line<'a>(puzzle_input: &'a str, index: usize, label: &str) -> Result<&'a str, ParseError> {
+    let line = puzzle_input.lines().nth(index).ok_or_else(|| {
+        ParseError::missing_after(puzzle_input, format!("expected a \"{label}\" line"))
+    })?;
+
+    line.strip_prefix(label).ok_or_else(|| {
+        ParseError::new(line, format!("expected the line to start with \"{label}\""))
+    })
+}
+
+pub fn parse_input_part1(puzzle_input: &str) -> Result<Vec<Race>, ParseError> {
+    let times = parse_line(puzzle_input, 0, "Time:")?
+        .split_ascii_whitespace()
+        .map(parse_number)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let records_line = parse_line(puzzle_input, 1, "Distance:")?;
+    let records = records_line
+        .split_ascii_whitespace()
+        .map(parse_number)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if times.len() != records.len() {
+        return Err(ParseError::new(
+            records_line,
+            format!("expected {} distances, one for each time", times.len()),
+        ));
+    }
+
+    Ok(times
+        .into_iter()
+        .zip(records)
+        .map(|(time, record)| Race { time, record })
+        .collect())
+}
+
+// in part 2 the spaces between the digits are ignored
+fn parse_kerned_number(numbers: &str) -> Result<usize, ParseError> {
+    let mut result = 0;
+    for c in numbers.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            result *= 10;
+            result += digit as usize;
+        } else if !c.is_ascii_whitespace() {
+            return Err(ParseError::new(numbers, "expected only digits and spaces"));
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn parse_input_part2(puzzle_input: &str) -> Result<Race, ParseError> {
+    let time = parse_kerned_number(parse_line(puzzle_input, 0, "Time:")?)?;
+    let record = parse_kerned_number(parse_line(puzzle_input, 1, "Distance:")?)?;
+
+    Ok(Race { time, record })
+}
+
+pub fn part1(races: &[Race]) -> usize {
+    races.iter().map(Race::num_elite_hold_times).product()
+}
+
+pub fn part2(race: &Race) -> usize {
+    race.num_elite_hold_times()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    // the input is read as several races in part 1 and as a single race in part 2
+    type Parsed<'a> = (Vec<Race>, Race);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((parse_input_part1(input)?, parse_input_part2(input)?))
+    }
+
+    fn part1((races, _): &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part1(races))
+    }
+
+    fn part2((_, race): &Self::Parsed<'_>) -> Result<usize> {
+        Ok(part2(race))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "Time:      7  15   30\n\
+                              Distance:  9  40  200";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_input_part1(TEST_INPUT).unwrap()), 288);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_input_part2(TEST_INPUT).unwrap()), 71503);
+    }
+}
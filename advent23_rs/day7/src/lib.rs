@@ -0,0 +1,290 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+use serde::Serialize;
+use std::{cmp::Reverse, iter::repeat};
+
+use num::FromPrimitive;
+use num_derive::FromPrimitive;
+use variant_count::VariantCount;
+
+// allow to sort on hand type (for example five of a kind is best)
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/*
+ * VariantCount is just used to get the amount of types of cards (14)
+ * for use in counting cards into an array of size (num of types of card)
+ * where index = card as usize,
+ * and value is the number of times the card appears in a hand.
+ *
+ * from primitive allows me to cast to a card instead of writing out 2-9 lol.
+ *
+ * others are for use in sorting on and copying cards
+ */
+#[derive(
+    Copy,
+    Clone,
+    FromPrimitive,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    VariantCount,
+    Serialize, /*, Hash */
+)]
+enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+type Hand = [Card; 5];
+
+#[derive(Clone, Serialize)]
+pub struct HandAndBid(Hand, i32);
+
+impl TryFrom<u8> for Card {
+    type Error = u8;
+
+    // uses J as Jack
+    fn try_from(value: u8) -> Result<Self, u8> {
+        use Card::*;
+        match value {
+            b'A' => Ok(Ace),
+            b'K' => Ok(King),
+            b'Q' => Ok(Queen),
+            b'J' => Ok(Jack),
+            b'T' => Ok(Ten),
+            b'2'..=b'9' => Ok(Card::from_u8(value - b'1').unwrap()), // assuming Two = 1
+            _ => Err(value),
+        }
+    }
+}
+
+type CardCounts = [i32; Card::VARIANT_COUNT];
+
+trait HandExt: Sized {
+    // ex of a valid hand: "46645"
+    fn from_part1(value: &str) -> Result<Self, ParseError>;
+    fn from_part2(value: &str) -> Result<Self, ParseError>;
+
+    fn count_hand(&self) -> CardCounts;
+    fn get_hand_type(&self) -> HandType;
+}
+
+impl HandExt for Hand {
+    fn from_part1(value: &str) -> Result<Self, ParseError> {
+        if value.len() != 5 {
+            return Err(ParseError::new(value, "expected a hand of 5 cards"));
+        }
+
+        let mut cards = [Card::Joker; 5];
+        for (i, card) in value.bytes().enumerate() {
+            cards[i] = card.try_into().map_err(|_| {
+                ParseError::from_bytes(
+                    &value.as_bytes()[i..=i],
+                    "expected a card: A, K, Q, J, T or 2-9",
+                )
+            })?;
+        }
+
+        Ok(cards)
+    }
+
+    // in part 2, Js are Jokers instead of Jacks
+    fn from_part2(value: &str) -> Result<Self, ParseError> {
+        let mut cards = Hand::from_part1(value)?;
+        for card in cards.iter_mut().filter(|card| **card == Card::Jack) {
+            *card = Card::Joker;
+        }
+
+        Ok(cards)
+    }
+
+    fn count_hand(&self) -> CardCounts {
+        // sets all the values to 0 at start
+        let mut result: CardCounts = Default::default();
+
+        for &card in self.iter() {
+            result[card as usize] += 1;
+        }
+
+        result
+    }
+
+    fn get_hand_type(&self) -> HandType {
+        use HandType::*;
+
+        let mut counts_map: CardCounts = self.count_hand();
+
+        let joker_amt = counts_map[Card::Joker as usize];
+        if joker_amt == 5 {
+            return FiveOfAKind;
+        }
+
+        // if hand contains a joker, set it to the most common non joker
+        if joker_amt != 0 {
+            let most_common_non_joker = counts_map
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != Card::Joker as usize)
+                .max_by(|(_, count1), (_, count2)| count1.cmp(count2))
+                .unwrap();
+
+            counts_map[most_common_non_joker.0] += counts_map[Card::Joker as usize];
+            counts_map[Card::Joker as usize] = 0;
+        }
+
+        // ex: AAQ2A => [3, 1, 1, 0, 0]
+        // place the card amounts in an array on the stack then fill excess spots with 0
+        // iterator is infinite so unwrap won't panic :)
+        let mut counts: [i32; 5] =
+            array_init::from_iter(counts_map.into_iter().filter(|x| *x != 0).chain(repeat(0)))
+                .unwrap();
+
+        // sort the card amounts from highest to lowest
+        counts.sort_unstable_by_key(|&x| Reverse(x));
+
+        // get the hand type from the counts
+        match counts[0] {
+            5 => FiveOfAKind,
+            4 => FourOfAKind,
+            3 => {
+                if counts[1] == 2 {
+                    FullHouse
+                } else {
+                    ThreeOfAKind
+                }
+            }
+            2 => {
+                if counts[1] == 2 {
+                    TwoPair
+                } else {
+                    OnePair
+                }
+            }
+            1 => HighCard,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl TryFrom<&str> for HandAndBid {
+    type Error = ParseError;
+
+    // converts one line of input into a hand and bid pair
+    // ex: "3J399 27" => HandAndBid([Three, Jack, Three, Nine, Nine], 27)
+    fn try_from(line: &str) -> Result<Self, ParseError> {
+        let (cards, bid_str) = split_hand_and_bid(line)?;
+        Ok(HandAndBid(Hand::from_part1(cards)?, parse_number(bid_str)?))
+    }
+}
+
+impl HandAndBid {
+    // use the part2 conversion instead (J becomes Joker, not Jack)
+    // ex: "3J399 27" => HandAndBid([Three, Joker, Three, Nine, Nine], 27)
+    fn try_from_part2(line: &str) -> Result<Self, ParseError> {
+        let (cards, bid_str) = split_hand_and_bid(line)?;
+        Ok(HandAndBid(Hand::from_part2(cards)?, parse_number(bid_str)?))
+    }
+}
+
+fn split_hand_and_bid(line: &str) -> Result<(&str, &str), ParseError> {
+    line.split_once(' ')
+        .ok_or_else(|| ParseError::new(line, "expected a hand and a bid like \"32T3K 765\""))
+}
+
+pub fn parse_input_part1(puzzle_input: &str) -> Result<Vec<HandAndBid>, ParseError> {
+    puzzle_input.lines().map(HandAndBid::try_from).collect()
+}
+
+// use the part2 conversion instead (J becomes Joker, not Jack)
+pub fn parse_input_part2(puzzle_input: &str) -> Result<Vec<HandAndBid>, ParseError> {
+    puzzle_input
+        .lines()
+        .map(HandAndBid::try_from_part2)
+        .collect()
+}
+
+fn total_winnings(mut hands_and_bids: Vec<HandAndBid>) -> i32 {
+    // sort by cards first, then sort by hand type while preserving the order from cards
+    hands_and_bids.sort_unstable_by_key(|hab| hab.0);
+    hands_and_bids.sort_by_key(|hab| hab.0.get_hand_type());
+
+    // sum the bids (hab.1) multiplied by rank (i + 1)
+    hands_and_bids
+        .iter()
+        .enumerate()
+        .map(|(i, hab)| ((i + 1) as i32) * hab.1)
+        .sum()
+}
+
+pub fn part1(hands_and_bids: &[HandAndBid]) -> i32 {
+    total_winnings(hands_and_bids.to_vec())
+}
+
+// the hands and bids must have been parsed with parse_input_part2
+pub fn part2(hands_and_bids: &[HandAndBid]) -> i32 {
+    total_winnings(hands_and_bids.to_vec())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    // J is read as a Jack in part 1 and as a Joker in part 2
+    type Parsed<'a> = (Vec<HandAndBid>, Vec<HandAndBid>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((parse_input_part1(input)?, parse_input_part2(input)?))
+    }
+
+    fn part1((hands_and_bids, _): &Self::Parsed<'_>) -> Result<i32> {
+        Ok(part1(hands_and_bids))
+    }
+
+    fn part2((_, hands_and_bids): &Self::Parsed<'_>) -> Result<i32> {
+        Ok(part2(hands_and_bids))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "32T3K 765\n\
+                              T55J5 684\n\
+                              KK677 28\n\
+                              KTJJT 220\n\
+                              QQQJA 483";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_input_part1(TEST_INPUT).unwrap()), 6440);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_input_part2(TEST_INPUT).unwrap()), 5905);
+    }
+}
@@ -0,0 +1,305 @@
+use anyhow::{bail, ensure, Context, Result};
+use common::{CancellationToken, ParseError, Solution};
+use graph::NodeId;
+use lazy_static::lazy_static;
+use num::integer::lcm;
+use regex::Regex;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Serialize)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+// every node has 2 successors, the left one first
+pub type Graph<'a> = graph::Graph<&'a str>;
+
+// where the instruction leads from node
+fn next_node(graph: &Graph, node: NodeId, instruction: Direction) -> NodeId {
+    graph.successors(node)[instruction as usize].0
+}
+
+fn follow_directions(
+    instructions: &[Direction],
+    graph: &Graph,
+    start: NodeId,
+    goal: NodeId,
+    cancel: &CancellationToken,
+) -> Result<usize> {
+    // once every (instruction, node) pair could have come up the walk is going round in a
+    // circle that misses goal
+    let max_steps = instructions.len() * graph.len();
+
+    let mut current = start;
+    for (i, &instruction) in instructions.iter().cycle().enumerate() {
+        if current == goal {
+            return Ok(i);
+        }
+        if i == max_steps {
+            bail!(
+                "{} is never reached from {}",
+                graph.name(goal),
+                graph.name(start)
+            );
+        }
+        cancel.tick(i as u64)?;
+
+        current = next_node(graph, current, instruction);
+    }
+
+    unreachable!()
+}
+
+pub fn part1(
+    instructions: &[Direction],
+    graph: &Graph,
+    cancel: &CancellationToken,
+) -> Result<usize> {
+    // follow instructions repeatedly on graph until you get to ZZZ
+    // return the number of instructions followed
+    let start = graph.id(&"AAA").context("there is no node called AAA")?;
+    let goal = graph.id(&"ZZZ").context("there is no node called ZZZ")?;
+
+    follow_directions(instructions, graph, start, goal, cancel)
+}
+
+struct GhostPathInfo {
+    cycle_length: usize, // in terms of time steps.
+}
+
+// returns the cycle length. part2 only works if the ghost is on a Z every time its walk comes
+// round, which is checked
+fn follow_directions_part2(
+    instructions: &[Direction],
+    graph: &Graph,
+    start: NodeId,
+) -> Result<GhostPathInfo> {
+    // state = (index of instructions, current node), which repeats once the
+    // ghost is on a cycle
+    let step = |(i, current): &mut (usize, NodeId)| {
+        *current = next_node(graph, *current, instructions[*i]);
+        *i = (*i + 1) % instructions.len();
+    };
+
+    let cycle = cycle::brent(&(0, start), step);
+
+    let mut state = (0, start);
+    for _ in 0..cycle.period {
+        step(&mut state);
+    }
+    ensure!(
+        cycle.prefix_len <= cycle.period && graph.name(state.1).ends_with('Z'),
+        "expected the ghost from {} to be on a node ending in Z every {} steps, when its walk \
+         repeats",
+        graph.name(start),
+        cycle.period
+    );
+
+    Ok(GhostPathInfo {
+        cycle_length: cycle.period,
+    })
+}
+
+pub fn part2(instructions: &[Direction], graph: &Graph) -> Result<usize> {
+    let ghost_path_infos = graph
+        .nodes()
+        .filter(|&node| graph.name(node).ends_with('A'))
+        .map(|start| follow_directions_part2(instructions, graph, start))
+        .collect::<Result<Vec<_>>>()?;
+
+    // OKAY bruh they each only go to 1 Z repeatedly in the real data so as a
+    // shortcut we simply get the LCM of all the dists
+    // otherwise it would have been way harder mafs lol
+
+    // checking if all are on Z until the longest prefix before the cycles, then
+    // solving a real large system of linear equations lol
+    // up to (num As)**(num As) times (once for every possible appearance of Z for every A)
+    ghost_path_infos
+        .iter()
+        .map(|gpi| gpi.cycle_length)
+        .reduce(lcm)
+        .context("expected a node ending in A for a ghost to start from")
+}
+
+pub fn parse_input(puzzle_input: &str) -> Result<(Vec<Direction>, Graph<'_>), ParseError> {
+    let mut iter = puzzle_input.lines();
+    let instructions_line = iter.next().unwrap_or_default();
+    let instructions = instructions_line
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseError::new(
+                &instructions_line[i..i + c.len_utf8()],
+                "instructions must be 'L' or 'R'",
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if instructions.is_empty() {
+        return Err(ParseError::new(
+            instructions_line,
+            "expected a line of 'L' and 'R' instructions",
+        ));
+    }
+
+    // empty line
+    iter.next();
+
+    let mut graph = Graph::new();
+    for line in iter {
+        // ex line: "NQT = (TXC, RVJ)"
+        lazy_static! {
+            static ref LINE_REGEX: Regex = Regex::new(r#"^(\w+) = \((\w+), (\w+)\)$"#).unwrap();
+        }
+
+        let (_, [node, neighbor1, neighbor2]) = LINE_REGEX
+            .captures(line.trim())
+            .ok_or_else(|| ParseError::new(line, "expected a line like \"AAA = (BBB, CCC)\""))?
+            .extract();
+
+        let node_id = graph.intern(node);
+        if !graph.successors(node_id).is_empty() {
+            return Err(ParseError::new(
+                node,
+                "expected each node to only have one line",
+            ));
+        }
+
+        for neighbor in [neighbor1, neighbor2] {
+            let neighbor = graph.intern(neighbor);
+            graph.add_edge(node_id, neighbor, 1);
+        }
+    }
+
+    // every node that can be walked to needs its own line
+    if let Some(neighbor) = graph
+        .nodes()
+        .find(|&node| graph.successors(node).is_empty())
+    {
+        return Err(ParseError::new(
+            graph.name(neighbor),
+            "expected every node to have a line with its neighbors",
+        ));
+    }
+
+    Ok((instructions, graph))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = (Vec<Direction>, Graph<'a>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<usize> {
+        Self::part1_cancellable(parsed, &CancellationToken::new())
+    }
+
+    fn part2((instructions, graph): &Self::Parsed<'_>) -> Result<usize> {
+        part2(instructions, graph)
+    }
+
+    fn part1_cancellable(
+        (instructions, graph): &Self::Parsed<'_>,
+        cancel: &CancellationToken,
+    ) -> Result<usize> {
+        part1(instructions, graph, cancel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_A: &str = "RL\n\n\
+                                AAA = (BBB, CCC)\n\
+                                BBB = (DDD, EEE)\n\
+                                CCC = (ZZZ, GGG)\n\
+                                DDD = (DDD, DDD)\n\
+                                EEE = (EEE, EEE)\n\
+                                GGG = (GGG, GGG)\n\
+                                ZZZ = (ZZZ, ZZZ)";
+
+    const TEST_INPUT_B: &str = "LLR\n\n\
+                                AAA = (BBB, BBB)\n\
+                                BBB = (AAA, ZZZ)\n\
+                                ZZZ = (ZZZ, ZZZ)";
+
+    const TEST_INPUT_C: &str = "LR\n\n\
+                                11A = (11B, XXX)
+                                11B = (XXX, 11Z)
+                                11Z = (11B, XXX)
+                                22A = (22B, XXX)
+                                22B = (22C, 22C)
+                                22C = (22Z, 22Z)
+                                22Z = (22B, 22B)
+                                XXX = (XXX, XXX)";
+
+    #[test]
+    fn test_part1_a() {
+        let (instructions, graph) = parse_input(TEST_INPUT_A).unwrap();
+
+        assert_eq!(
+            part1(&instructions, &graph, &CancellationToken::new()).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_part1_b() {
+        let (instructions, graph) = parse_input(TEST_INPUT_B).unwrap();
+
+        assert_eq!(
+            part1(&instructions, &graph, &CancellationToken::new()).unwrap(),
+            6
+        );
+    }
+
+    #[test]
+    fn test_part1_unreachable() {
+        // ZZZ is only reachable by going left from BBB, but BBB is always reached going right
+        let (instructions, graph) =
+            parse_input("R\n\nAAA = (AAA, BBB)\nBBB = (ZZZ, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+
+        let err = part1(&instructions, &graph, &CancellationToken::new()).unwrap_err();
+        assert_eq!(err.to_string(), "ZZZ is never reached from AAA");
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let err = part1(&instructions, &graph, &cancel).unwrap_err();
+        assert_eq!(err.to_string(), "cancelled after 0 steps");
+    }
+
+    #[test]
+    fn test_part2() {
+        let (instructions, graph) = parse_input(TEST_INPUT_C).unwrap();
+
+        assert_eq!(part2(&instructions, &graph).unwrap(), 6);
+
+        let (instructions, graph) = parse_input("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let err = part2(&instructions, &graph).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a node ending in A for a ghost to start from"
+        );
+
+        // the ghost gets to 11Z once, then goes round between 11B and 11C
+        let (instructions, graph) = parse_input(
+            "L\n\n11A = (11Z, 11A)\n11Z = (11B, 11B)\n11B = (11C, 11C)\n11C = (11B, 11B)",
+        )
+        .unwrap();
+        let err = part2(&instructions, &graph).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected the ghost from 11A to be on a node ending in Z every 2 steps, when its walk \
+             repeats"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use common::{parse_number, ParseError, Solution};
+use num::PrimInt;
+
+trait History<T: PrimInt> {
+    fn find_next_value(&self) -> T;
+    fn find_prev_value(&self) -> T;
+}
+
+impl<T: PrimInt> History<T> for Vec<T> {
+    fn find_next_value(&self) -> T {
+        if self.iter().all(|x| x.is_zero()) {
+            return T::zero();
+        }
+
+        let next_layer_val = self
+            .windows(2)
+            .map(|arr| arr[1] - arr[0])
+            .collect::<Vec<_>>()
+            .find_next_value();
+
+        self[self.len() - 1] + next_layer_val
+    }
+
+    fn find_prev_value(&self) -> T {
+        if self.iter().all(|x| x.is_zero()) {
+            return T::zero();
+        }
+
+        let next_layer_val = self
+            .windows(2)
+            .map(|arr| arr[1] - arr[0])
+            .collect::<Vec<_>>()
+            .find_prev_value();
+
+        self[0] - next_layer_val
+    }
+}
+
+fn parse_history(line: &str) -> Result<Vec<i32>, ParseError> {
+    line.split_ascii_whitespace().map(parse_number).collect()
+}
+
+pub fn parse_input(puzzle_input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    puzzle_input.lines().map(parse_history).collect()
+}
+
+pub fn part1(histories: &[Vec<i32>]) -> i32 {
+    histories
+        .iter()
+        .map(|history| history.find_next_value())
+        .sum()
+}
+
+pub fn part2(histories: &[Vec<i32>]) -> i32 {
+    histories
+        .iter()
+        .map(|history| history.find_prev_value())
+        .sum()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed<'a> = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(histories: &Self::Parsed<'_>) -> Result<i32> {
+        Ok(part1(histories))
+    }
+
+    fn part2(histories: &Self::Parsed<'_>) -> Result<i32> {
+        Ok(part2(histories))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "0 3 6 9 12 15\n\
+                              1 3 6 10 15 21\n\
+                              10 13 16 21 30 45";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 114);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 2);
+    }
+}
@@ -0,0 +1,413 @@
+use serde::{Serialize, Serializer};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{Display, Write},
+    hash::Hash,
+    ops::Range,
+};
+
+// nodes are numbered 0, 1, 2, ... in the order their names are first seen
+pub type NodeId = u32;
+
+// a directed graph with weighted edges. undirected graphs add an edge each way
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    names: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    // the (successor, weight) of every edge out of each node, in the order they were added
+    successors: Vec<Vec<(NodeId, usize)>>,
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Graph {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            successors: Vec::new(),
+        }
+    }
+
+    // the id of the node called name, which is added without any edges if it's new
+    pub fn intern(&mut self, name: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&name) {
+            return id;
+        }
+
+        let id = self.names.len() as NodeId;
+        self.names.push(name.clone());
+        self.ids.insert(name, id);
+        self.successors.push(Vec::new());
+        id
+    }
+
+    pub fn id(&self, name: &N) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &N {
+        &self.names[id as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> Range<NodeId> {
+        0..self.len() as NodeId
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: usize) {
+        self.successors[from as usize].push((to, weight));
+    }
+
+    pub fn successors(&self, id: NodeId) -> &[(NodeId, usize)] {
+        &self.successors[id as usize]
+    }
+
+    // every (from, to, weight) edge
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId, usize)> + '_ {
+        self.nodes().flat_map(move |from| {
+            self.successors(from)
+                .iter()
+                .map(move |&(to, weight)| (from, to, weight))
+        })
+    }
+
+    // the same nodes with the same ids, with every edge pointing the other way
+    pub fn reversed(&self) -> Self {
+        let mut successors = vec![Vec::new(); self.len()];
+        for (from, to, weight) in self.edges() {
+            successors[to as usize].push((from, weight));
+        }
+
+        Graph {
+            names: self.names.clone(),
+            ids: self.ids.clone(),
+            successors,
+        }
+    }
+
+    // the same graph with the same ids and renamed nodes, f has to give every node a different name
+    pub fn map_names<M: Clone + Eq + Hash>(&self, mut f: impl FnMut(&N) -> M) -> Graph<M> {
+        let names = self.names.iter().map(&mut f).collect::<Vec<_>>();
+        let ids = names
+            .iter()
+            .enumerate()
+            .map(|(id, name)| (name.clone(), id as NodeId))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(ids.len(), names.len(), "renamed two nodes to the same name");
+
+        Graph {
+            names,
+            ids,
+            successors: self.successors.clone(),
+        }
+    }
+
+    // the number of edges on the shortest path from start to each node, ignoring weights
+    pub fn bfs(&self, start: NodeId) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.len()];
+        distances[start as usize] = Some(0);
+        let mut queue = VecDeque::from([(start, 0)]);
+
+        while let Some((node, distance)) = queue.pop_front() {
+            for &(successor, _) in self.successors(node) {
+                if distances[successor as usize].is_none() {
+                    distances[successor as usize] = Some(distance + 1);
+                    queue.push_back((successor, distance + 1));
+                }
+            }
+        }
+
+        distances
+    }
+
+    // every node reachable from start, in the order a recursive depth first search visits them
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(node) = stack.pop() {
+            if seen[node as usize] {
+                continue;
+            }
+            seen[node as usize] = true;
+            order.push(node);
+
+            // reversed so the first successor is popped first
+            stack.extend(
+                self.successors(node)
+                    .iter()
+                    .rev()
+                    .map(|&(successor, _)| successor)
+                    .filter(|&successor| !seen[successor as usize]),
+            );
+        }
+
+        order
+    }
+
+    // tarjan's algorithm, with its recursion on an explicit stack. every node is in exactly one
+    // component, and components come after every component they have edges to
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        const UNVISITED: usize = usize::MAX;
+
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low_link = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in self.nodes() {
+            if index[root as usize] != UNVISITED {
+                continue;
+            }
+
+            // (node, how many of its successors have been looked at)
+            let mut call_stack = vec![(root, 0)];
+            index[root as usize] = next_index;
+            low_link[root as usize] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root as usize] = true;
+
+            while let Some(&(node, num_seen)) = call_stack.last() {
+                let node_index = node as usize;
+
+                if let Some(&(successor, _)) = self.successors(node).get(num_seen) {
+                    call_stack.last_mut().unwrap().1 += 1;
+                    let successor_index = successor as usize;
+
+                    if index[successor_index] == UNVISITED {
+                        index[successor_index] = next_index;
+                        low_link[successor_index] = next_index;
+                        next_index += 1;
+                        stack.push(successor);
+                        on_stack[successor_index] = true;
+                        call_stack.push((successor, 0));
+                    } else if on_stack[successor_index] {
+                        low_link[node_index] = low_link[node_index].min(index[successor_index]);
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    let parent_index = parent as usize;
+                    low_link[parent_index] = low_link[parent_index].min(low_link[node_index]);
+                }
+
+                if low_link[node_index] == index[node_index] {
+                    let mut component = Vec::new();
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member as usize] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    // kahn's algorithm, every node comes before its successors. None if there's a cycle
+    pub fn topological_sort(&self) -> Option<Vec<NodeId>> {
+        let mut in_degree = vec![0; self.len()];
+        for (_, to, _) in self.edges() {
+            in_degree[to as usize] += 1;
+        }
+
+        let mut order = self
+            .nodes()
+            .filter(|&node| in_degree[node as usize] == 0)
+            .collect::<Vec<_>>();
+
+        let mut i = 0;
+        while let Some(&node) = order.get(i) {
+            for &(successor, _) in self.successors(node) {
+                in_degree[successor as usize] -= 1;
+                if in_degree[successor as usize] == 0 {
+                    order.push(successor);
+                }
+            }
+            i += 1;
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+}
+
+impl<N: Display> Graph<N> {
+    // GraphViz DOT, nodes are named by their ids and labelled with their names. weights other
+    // than 1 label their edges
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+
+        for (id, name) in self.names.iter().enumerate() {
+            let label = name.to_string().replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(dot, "    {id} [label=\"{label}\"];").unwrap();
+        }
+
+        for (from, successors) in self.successors.iter().enumerate() {
+            for &(to, weight) in successors {
+                if weight == 1 {
+                    writeln!(dot, "    {from} -> {to};").unwrap();
+                } else {
+                    writeln!(dot, "    {from} -> {to} [label={weight}];").unwrap();
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+// a node as it's serialized, with its edges pointing at the names of their successors
+#[derive(Serialize)]
+struct SerializedNode<'a, N> {
+    name: &'a N,
+    successors: Vec<(&'a N, usize)>,
+}
+
+// the nodes in id order, each with its (successor, weight) edges
+impl<N: Serialize> Serialize for Graph<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.names
+                .iter()
+                .zip(&self.successors)
+                .map(|(name, successors)| SerializedNode {
+                    name,
+                    successors: successors
+                        .iter()
+                        .map(|&(id, weight)| (&self.names[id as usize], weight))
+                        .collect(),
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -> b -> c -> a, c -> d -> e, d -> f, with e -> d weighing 4
+    fn example() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for (from, to, weight) in [
+            ("a", "b", 1),
+            ("b", "c", 1),
+            ("c", "a", 1),
+            ("c", "d", 1),
+            ("d", "e", 1),
+            ("e", "d", 4),
+            ("d", "f", 1),
+        ] {
+            let (from, to) = (graph.intern(from), graph.intern(to));
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut graph = example();
+
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.id(&"d"), Some(3));
+        assert_eq!(graph.intern("d"), 3);
+        assert_eq!(graph.intern("g"), 6);
+        assert_eq!(*graph.name(6), "g");
+        assert_eq!(graph.id(&"h"), None);
+        assert_eq!(graph.successors(3), [(4, 1), (5, 1)]);
+        assert_eq!(graph.reversed().successors(3), [(2, 1), (4, 4)]);
+    }
+
+    #[test]
+    fn test_search() {
+        let graph = example();
+
+        assert_eq!(
+            graph.bfs(0),
+            [Some(0), Some(1), Some(2), Some(3), Some(4), Some(4)]
+        );
+        assert_eq!(graph.bfs(4), [None, None, None, Some(1), Some(0), Some(2)]);
+        assert_eq!(graph.dfs(0), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(graph.dfs(3), [3, 4, 5]);
+    }
+
+    #[test]
+    fn test_components() {
+        let mut components = example().strongly_connected_components();
+        for component in &mut components {
+            component.sort();
+        }
+
+        assert_eq!(components, [vec![5], vec![3, 4], vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        assert_eq!(example().topological_sort(), None);
+
+        let mut graph = Graph::new();
+        for (from, to) in [("shirt", "tie"), ("socks", "shoes"), ("tie", "jacket")] {
+            let (from, to) = (graph.intern(from), graph.intern(to));
+            graph.add_edge(from, to, 1);
+        }
+        let order = graph.topological_sort().unwrap();
+
+        for (from, to, _) in graph.edges() {
+            let position = |node| order.iter().position(|&other| other == node);
+            assert!(position(from) < position(to));
+        }
+    }
+
+    #[test]
+    fn test_dot() {
+        let mut graph = Graph::new();
+        let (a, b) = (graph.intern("a"), graph.intern("say \"b\""));
+        graph.add_edge(a, b, 1);
+        graph.add_edge(b, a, 2);
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    \
+                 0 [label=\"a\"];\n    \
+                 1 [label=\"say \\\"b\\\"\"];\n    \
+                 0 -> 1;\n    \
+                 1 -> 0 [label=2];\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut graph = Graph::new();
+        let (a, b) = (graph.intern("a"), graph.intern("b"));
+        graph.add_edge(a, b, 3);
+
+        assert_eq!(
+            serde_json::to_string(&graph).unwrap(),
+            r#"[{"name":"a","successors":[["b",3]]},{"name":"b","successors":[]}]"#
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::Position;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+// the turn needed to get from one direction to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Straight,
+    Right,
+    Backwards,
+    Left,
+}
+
+impl Direction {
+    // clockwise order starting from North
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        Direction::ALL.into_iter()
+    }
+
+    // (row offset, column offset) of one step in this direction
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn(Turn::Backwards)
+    }
+
+    pub fn is_opposite(&self, other: Self) -> bool {
+        self.opposite() == other
+    }
+
+    pub fn turn_right(&self) -> Self {
+        self.turn(Turn::Right)
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.turn(Turn::Left)
+    }
+
+    pub fn turn(&self, turn: Turn) -> Self {
+        Direction::ALL[(*self as usize + turn as usize) % 4]
+    }
+
+    // the turn that was made to face self after facing prev_dir
+    pub fn turn_since(&self, prev_dir: Direction) -> Turn {
+        match (*self as usize + 4 - prev_dir as usize) % 4 {
+            0 => Turn::Straight,
+            1 => Turn::Right,
+            2 => Turn::Backwards,
+            _ => Turn::Left,
+        }
+    }
+
+    // take one step from position, returns None if either coordinate would underflow
+    pub fn step(&self, (row, column): Position) -> Option<Position> {
+        let (row_offset, column_offset) = self.offset();
+
+        Some((
+            row.checked_add_signed(row_offset)?,
+            column.checked_add_signed(column_offset)?,
+        ))
+    }
+}
@@ -0,0 +1,448 @@
+use serde::{Serialize, Serializer};
+use std::{
+    error, fmt,
+    ops::{Index, IndexMut},
+};
+
+mod direction;
+
+pub use direction::{Direction, Turn};
+
+// (row, column) position in a grid
+pub type Position = (usize, usize);
+
+// offsets of all 8 neighbors, clockwise starting from north
+const NEIGHBOR_OFFSETS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    // the input had no rows or no columns
+    Empty,
+    // a row (1-indexed line) was a different length than the first row
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    // a character could not be converted into a cell, line and column are 1-indexed
+    InvalidCell {
+        line: usize,
+        column: usize,
+        byte: u8,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            GridError::Empty => write!(f, "grid is empty"),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line}: expected a row of length {expected}, found length {found}"
+            ),
+            GridError::InvalidCell { line, column, byte } => write!(
+                f,
+                "line {line}, column {column}: cannot parse '{}' into a grid cell",
+                byte.escape_ascii()
+            ),
+        }
+    }
+}
+
+impl error::Error for GridError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    // stored in row major order
+    cells: Vec<T>,
+    num_rows: usize,
+    num_columns: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn filled_with(value: T, num_rows: usize, num_columns: usize) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; num_rows * num_columns],
+            num_rows,
+            num_columns,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let num_rows = rows.len();
+        let num_columns = rows.first().map_or(0, Vec::len);
+
+        if num_columns == 0 {
+            return Err(GridError::Empty);
+        }
+
+        let mut cells = Vec::with_capacity(num_rows * num_columns);
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != num_columns {
+                return Err(GridError::Ragged {
+                    line: i + 1,
+                    expected: num_columns,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            cells,
+            num_rows,
+            num_columns,
+        })
+    }
+
+    // panics if the iterator does not contain exactly num_rows * num_columns elements
+    pub fn from_iter_row_major(
+        iter: impl IntoIterator<Item = T>,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> Self {
+        let cells = iter.into_iter().collect::<Vec<_>>();
+        assert_eq!(cells.len(), num_rows * num_columns);
+
+        Grid {
+            cells,
+            num_rows,
+            num_columns,
+        }
+    }
+
+    // parse a char map where every byte is a cell, one row per line
+    pub fn parse(input: &str) -> Result<Self, GridError>
+    where
+        T: TryFrom<u8>,
+    {
+        Grid::parse_with(input, |c| T::try_from(c).ok())
+    }
+
+    pub fn parse_with(input: &str, mut f: impl FnMut(u8) -> Option<T>) -> Result<Self, GridError> {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.bytes()
+                    .enumerate()
+                    .map(|(j, c)| {
+                        f(c).ok_or(GridError::InvalidCell {
+                            line: i + 1,
+                            column: j + 1,
+                            byte: c,
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Grid::from_rows(rows)
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.num_rows && column < self.num_columns
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self[position])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self[position])
+        } else {
+            None
+        }
+    }
+
+    // move one step in direction, returns None if that would leave the grid
+    pub fn try_move(&self, position: Position, direction: Direction) -> Option<Position> {
+        direction.step(position).filter(|&next| self.contains(next))
+    }
+
+    // the up to 4 orthogonal neighbors within the grid, with the direction to get to them
+    pub fn neighbors4(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = (Direction, Position)> + '_ {
+        Direction::iter().filter_map(move |direction| {
+            self.try_move(position, direction)
+                .map(|next| (direction, next))
+        })
+    }
+
+    // the up to 8 orthogonal and diagonal neighbors within the grid
+    pub fn neighbors8(&self, (row, column): Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOR_OFFSETS_8
+            .iter()
+            .filter_map(move |&(row_offset, column_offset)| {
+                Some((
+                    row.checked_add_signed(row_offset)?,
+                    column.checked_add_signed(column_offset)?,
+                ))
+            })
+            .filter(|&next| self.contains(next))
+    }
+
+    // all positions in row major order
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let num_columns = self.num_columns;
+        (0..self.num_rows).flat_map(move |row| (0..num_columns).map(move |column| (row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i / self.num_columns, i % self.num_columns))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.num_columns..(row + 1) * self.num_columns]
+    }
+
+    pub fn rows_iter(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.num_columns)
+    }
+
+    pub fn column_iter(
+        &self,
+        column: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(column < self.num_columns);
+        self.cells[column..].iter().step_by(self.num_columns)
+    }
+
+    pub fn columns_iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator>
+           + ExactSizeIterator {
+        (0..self.num_columns).map(move |column| self.column_iter(column))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    pub fn map_with_position<U>(&self, mut f: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self
+                .enumerate()
+                .map(|(position, cell)| f(position, cell))
+                .collect(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): Position) -> &T {
+        assert!(
+            row < self.num_rows && column < self.num_columns,
+            "position ({row}, {column}) is out of bounds of a {}x{} grid",
+            self.num_rows,
+            self.num_columns
+        );
+        &self.cells[row * self.num_columns + column]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, column): Position) -> &mut T {
+        assert!(
+            row < self.num_rows && column < self.num_columns,
+            "position ({row}, {column}) is out of bounds of a {}x{} grid",
+            self.num_rows,
+            self.num_columns
+        );
+        &mut self.cells[row * self.num_columns + column]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows_iter() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// a list of rows, like the input it was parsed from
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.rows_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<u8> for Cell {
+        type Error = ();
+
+        fn try_from(value: u8) -> Result<Self, ()> {
+            match value {
+                b'.' => Ok(Cell::Open),
+                b'#' => Ok(Cell::Wall),
+                _ => Err(()),
+            }
+        }
+    }
+
+    impl fmt::Display for Cell {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Cell::Open => write!(f, "."),
+                Cell::Wall => write!(f, "#"),
+            }
+        }
+    }
+
+    const TEST_INPUT: &str = "..#\n\
+                              #..\n\
+                              ...";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::<Cell>::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(grid.num_rows(), 3);
+        assert_eq!(grid.num_columns(), 3);
+        assert_eq!(grid[(0, 2)], Cell::Wall);
+        assert_eq!(grid.to_string(), format!("{TEST_INPUT}\n"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Grid::<Cell>::parse("..\n.x"),
+            Err(GridError::InvalidCell {
+                line: 2,
+                column: 2,
+                byte: b'x'
+            })
+        );
+        assert_eq!(
+            Grid::<Cell>::parse("..\n."),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(Grid::<Cell>::parse(""), Err(GridError::Empty));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::<Cell>::parse(TEST_INPUT).unwrap();
+
+        let corner = grid.neighbors4((0, 0)).collect::<Vec<_>>();
+        assert_eq!(
+            corner,
+            [(Direction::East, (0, 1)), (Direction::South, (1, 0))]
+        );
+
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.try_move((2, 2), Direction::South), None);
+    }
+
+    #[test]
+    fn test_turns() {
+        use Direction::*;
+
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(East.opposite(), West);
+        assert_eq!(West.turn_since(South), Turn::Right);
+        assert_eq!(South.turn_since(West), Turn::Left);
+        assert!(North.is_opposite(South));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::<Cell>::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(grid.row(1), [Cell::Wall, Cell::Open, Cell::Open]);
+        assert_eq!(
+            grid.column_iter(2).rev().copied().collect::<Vec<_>>(),
+            [Cell::Open, Cell::Open, Cell::Wall]
+        );
+        assert_eq!(grid.columns_iter().len(), 3);
+        assert_eq!(grid.position(|&cell| cell == Cell::Wall), Some((0, 2)));
+
+        let walls =
+            grid.map_with_position(|position, &cell| (cell == Cell::Wall).then_some(position));
+        assert_eq!(walls[(0, 2)], Some((0, 2)));
+        assert_eq!(walls.iter().flatten().count(), 2);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let grid = Grid::<Cell>::parse(TEST_INPUT).unwrap();
+        let walls = grid.map(|&cell| u8::from(cell == Cell::Wall));
+
+        assert_eq!(
+            serde_json::to_string(&walls).unwrap(),
+            "[[0,0,1],[1,0,0],[0,0,0]]"
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use grid::Grid;
+use std::{fmt::Write, fs, io, path::Path};
+
+pub type Rgb = [u8; 3];
+
+// one pixel per cell, the top left pixel is (0, 0)
+pub type Image = Grid<Rgb>;
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+// the color a fraction t of the way from `from` to `to`
+pub fn lerp(from: Rgb, to: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    std::array::from_fn(|i| (from[i] as f64 + (to[i] as f64 - from[i] as f64) * t).round() as u8)
+}
+
+// every pixel blown up into a factor by factor square, tiny grids are hard to see otherwise
+pub fn scale(image: &Image, factor: usize) -> Image {
+    let num_rows = image.num_rows() * factor;
+    let num_columns = image.num_columns() * factor;
+
+    Grid::from_iter_row_major(
+        (0..num_rows).flat_map(|row| {
+            (0..num_columns).map(move |column| image[(row / factor, column / factor)])
+        }),
+        num_rows,
+        num_columns,
+    )
+}
+
+// binary PPM (P6), about the simplest image format there is
+pub fn to_ppm(image: &Image) -> Vec<u8> {
+    let mut ppm = format!("P6\n{} {}\n255\n", image.num_columns(), image.num_rows()).into_bytes();
+    ppm.extend(image.iter().flatten());
+    ppm
+}
+
+// 24 bit colour ANSI escapes for a terminal, two rows of pixels to a line. each character is an
+// upper half block in the colour of the top pixel on a background of the bottom pixel
+pub fn to_ansi(image: &Image) -> String {
+    let mut ansi = String::new();
+
+    for row in (0..image.num_rows()).step_by(2) {
+        for column in 0..image.num_columns() {
+            let [r, g, b] = image[(row, column)];
+            write!(ansi, "\x1b[38;2;{r};{g};{b}m").unwrap();
+            match image.get((row + 1, column)) {
+                Some([r, g, b]) => write!(ansi, "\x1b[48;2;{r};{g};{b}m\u{2580}").unwrap(),
+                // an odd number of rows leaves the bottom half of the last line empty
+                None => ansi.push_str("\x1b[49m\u{2580}"),
+            }
+        }
+        ansi.push_str("\x1b[0m\n");
+    }
+
+    ansi
+}
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+// the most a stored deflate block can hold
+const MAX_STORED_BLOCK: usize = 0xffff;
+
+// 8 bit RGB PNG. the pixels aren't compressed, they go in stored deflate blocks, which keeps
+// this short and free of dependencies at the cost of bigger files
+pub fn to_png(image: &Image) -> Vec<u8> {
+    let mut png = PNG_SIGNATURE.to_vec();
+
+    let mut header = Vec::new();
+    header.extend((image.num_columns() as u32).to_be_bytes());
+    header.extend((image.num_rows() as u32).to_be_bytes());
+    // bit depth 8, color type 2 (RGB), deflate, no filtering, not interlaced
+    header.extend([8, 2, 0, 0, 0]);
+    write_chunk(&mut png, b"IHDR", &header);
+
+    // each row starts with its filter type, 0 is none
+    let mut raw = Vec::with_capacity(image.num_rows() * (1 + 3 * image.num_columns()));
+    for row in image.rows_iter() {
+        raw.push(0);
+        raw.extend(row.iter().flatten());
+    }
+    write_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+// writes the image as PNG or PPM depending on the extension of path
+pub fn save(image: &Image, path: &Path) -> io::Result<()> {
+    let bytes = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("png") => to_png(image),
+        Some("ppm") => to_ppm(image),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} should end in .png or .ppm", path.display()),
+            ))
+        }
+    };
+
+    fs::write(path, bytes)
+}
+
+// length, type, data and the crc of the type and data
+fn write_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(chunk_type);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// a zlib stream of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // deflate with a 32K window, no preset dictionary, and the check bits that make it a
+    // multiple of 31
+    let mut stream = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        // an empty stream still needs a final block
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        // the block header is 3 bits, the final bit then type 00, padded to a byte
+        stream.push(is_final as u8);
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(block);
+    }
+
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+// the crc of every byte, for the reversed polynomial of crc-32
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut crc = n as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                0xedb88320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[n] = crc;
+        n += 1;
+    }
+    table
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        CRC_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    // 5552 is the most bytes that can be summed before b could overflow
+    let (mut a, mut b) = (1, 0);
+    for chunk in bytes.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Image {
+        Grid::from_rows(vec![
+            vec![BLACK, WHITE, [255, 0, 0]],
+            vec![[0, 255, 0], [0, 0, 255], lerp(BLACK, WHITE, 0.5)],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+        assert_eq!(adler32(&[0xff; 100_000]), 0x149a_302c);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = to_ppm(&example());
+
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(
+            &ppm[11..],
+            [0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 255, 0, 0, 0, 255, 128, 128, 128]
+        );
+    }
+
+    #[test]
+    fn test_ansi() {
+        let ansi = to_ansi(&example());
+        assert_eq!(ansi.lines().count(), 1);
+        assert!(ansi.starts_with("\x1b[38;2;0;0;0m\x1b[48;2;0;255;0m\u{2580}"));
+        assert!(ansi.ends_with("\x1b[38;2;255;0;0m\x1b[48;2;128;128;128m\u{2580}\x1b[0m\n"));
+
+        let odd = Grid::from_rows(vec![vec![WHITE], vec![BLACK], vec![WHITE]]).unwrap();
+        assert_eq!(
+            to_ansi(&odd),
+            "\x1b[38;2;255;255;255m\x1b[48;2;0;0;0m\u{2580}\x1b[0m\n\
+             \x1b[38;2;255;255;255m\x1b[49m\u{2580}\x1b[0m\n"
+        );
+    }
+
+    // the chunks of a png, checking their crcs
+    fn chunks(png: &[u8]) -> Vec<([u8; 4], &[u8])> {
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        let mut rest = &png[8..];
+        let mut chunks = Vec::new();
+
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let (chunk, crc) = rest[4..].split_at(4 + len);
+            assert_eq!(crc32(chunk).to_be_bytes(), crc[..4]);
+
+            chunks.push((chunk[..4].try_into().unwrap(), &chunk[4..]));
+            rest = &crc[4..];
+        }
+
+        chunks
+    }
+
+    // reads a zlib stream of stored blocks back
+    fn unzlib_stored(mut stream: &[u8]) -> Vec<u8> {
+        assert_eq!(u16::from_be_bytes([stream[0], stream[1]]) % 31, 0);
+        stream = &stream[2..];
+        let mut data = Vec::new();
+
+        loop {
+            let is_final = stream[0] == 1;
+            let len = u16::from_le_bytes([stream[1], stream[2]]);
+            assert_eq!(!len, u16::from_le_bytes([stream[3], stream[4]]));
+            data.extend(&stream[5..5 + len as usize]);
+            stream = &stream[5 + len as usize..];
+            if is_final {
+                break;
+            }
+        }
+
+        assert_eq!(stream, adler32(&data).to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn test_png() {
+        let png = to_png(&example());
+        let chunks = chunks(&png);
+
+        let types = chunks
+            .iter()
+            .map(|(chunk_type, _)| chunk_type)
+            .collect::<Vec<_>>();
+        assert_eq!(types, [b"IHDR", b"IDAT", b"IEND"]);
+        assert_eq!(chunks[0].1, [0, 0, 0, 3, 0, 0, 0, 2, 8, 2, 0, 0, 0]);
+        assert_eq!(
+            unzlib_stored(chunks[1].1),
+            [0, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 255, 0, 0, 0, 255, 128, 128, 128]
+        );
+    }
+
+    #[test]
+    fn test_big_png() {
+        // more than one stored block
+        let image = scale(&example(), 100);
+        assert_eq!((image.num_rows(), image.num_columns()), (200, 300));
+        assert_eq!(image[(150, 250)], [128, 128, 128]);
+
+        let raw = unzlib_stored(chunks(&to_png(&image))[1].1);
+        assert_eq!(raw.len(), 200 * (1 + 3 * 300));
+        assert_eq!(raw[1 + 3 * 299..1 + 3 * 300], [255, 0, 0]);
+    }
+}
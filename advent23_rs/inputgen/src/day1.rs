@@ -0,0 +1,55 @@
+use rand::{seq::SliceRandom, Rng};
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// size lines of lowercase letters with digits and spelled out digits mixed in. every line has at
+// least one real digit, so both parts find a calibration value in it
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let mut pieces = (0..rng.gen_range(1..=4))
+            .map(|_| rng.gen_range(b'1'..=b'9') as char)
+            .map(String::from)
+            .collect::<Vec<_>>();
+        for _ in 0..rng.gen_range(0..=3) {
+            pieces.push(DIGIT_WORDS.choose(rng).unwrap().to_string());
+        }
+        for _ in 0..rng.gen_range(0..=12) {
+            pieces.push((rng.gen_range(b'a'..=b'z') as char).to_string());
+        }
+
+        pieces.shuffle(rng);
+        input.push_str(&pieces.concat());
+        input.push('\n');
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day1::Day1;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for size in [1, 10, 5000] {
+            let input = generate(&mut rng, size);
+            let lines = Day1::parse(&input)?;
+
+            assert_eq!(lines.len(), size);
+            assert!(Day1::part1(&lines)? >= 11 * size as u32);
+            assert!(Day1::part2(&lines)? <= 99 * size as u32);
+        }
+
+        Ok(())
+    }
+}
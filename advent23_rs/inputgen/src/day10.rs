@@ -0,0 +1,249 @@
+use rand::{seq::SliceRandom, Rng};
+
+// indices into a cell's links
+const NORTH: usize = 0;
+const EAST: usize = 1;
+const SOUTH: usize = 2;
+const WEST: usize = 3;
+
+// the pipe for each pair of linked directions, and the directions each pipe links
+const PIPES: [(u8, [usize; 2]); 6] = [
+    (b'|', [NORTH, SOUTH]),
+    (b'-', [EAST, WEST]),
+    (b'L', [NORTH, EAST]),
+    (b'J', [NORTH, WEST]),
+    (b'7', [SOUTH, WEST]),
+    (b'F', [SOUTH, EAST]),
+];
+
+struct Pipes {
+    // which of the 4 neighbors each cell links to, in row major order
+    links: Vec<[bool; 4]>,
+    num_columns: usize,
+    // the side length of the square blocks the grid is split into
+    block_size: usize,
+}
+
+impl Pipes {
+    fn set(&mut self, (row, column): (usize, usize), direction: usize, linked: bool) {
+        self.links[row * self.num_columns + column][direction] = linked;
+    }
+
+    fn clear(&mut self, (row, column): (usize, usize)) {
+        self.links[row * self.num_columns + column] = [false; 4];
+    }
+
+    // a ring of pipes around the edge of the block at (block_row, block_column)
+    fn add_ring(&mut self, block_row: usize, block_column: usize) {
+        let (row, column) = (block_row * self.block_size, block_column * self.block_size);
+        let last = self.block_size - 1;
+
+        for offset in 1..last {
+            for (position, directions) in [
+                ((row, column + offset), [EAST, WEST]),
+                ((row + last, column + offset), [EAST, WEST]),
+                ((row + offset, column), [NORTH, SOUTH]),
+                ((row + offset, column + last), [NORTH, SOUTH]),
+            ] {
+                for direction in directions {
+                    self.set(position, direction, true);
+                }
+            }
+        }
+
+        for (position, directions) in [
+            ((row, column), [EAST, SOUTH]),
+            ((row, column + last), [SOUTH, WEST]),
+            ((row + last, column + last), [NORTH, WEST]),
+            ((row + last, column), [NORTH, EAST]),
+        ] {
+            for direction in directions {
+                self.set(position, direction, true);
+            }
+        }
+    }
+
+    // join the ring of a block with the ring of the block to its east or south. this removes the
+    // walls between them and links the corners across, which merges both rings into one loop
+    fn join(&mut self, (block_row, block_column): (usize, usize), direction: usize) {
+        let (row, column) = (block_row * self.block_size, block_column * self.block_size);
+        let last = self.block_size - 1;
+
+        if direction == EAST {
+            for (corner_row, away) in [(row, SOUTH), (row + last, NORTH)] {
+                self.set((corner_row, column + last), away, false);
+                self.set((corner_row, column + last), EAST, true);
+                self.set((corner_row, column + last + 1), away, false);
+                self.set((corner_row, column + last + 1), WEST, true);
+            }
+            for wall_row in row + 1..row + last {
+                self.clear((wall_row, column + last));
+                self.clear((wall_row, column + last + 1));
+            }
+        } else {
+            for (corner_column, away) in [(column, EAST), (column + last, WEST)] {
+                self.set((row + last, corner_column), away, false);
+                self.set((row + last, corner_column), SOUTH, true);
+                self.set((row + last + 1, corner_column), away, false);
+                self.set((row + last + 1, corner_column), NORTH, true);
+            }
+            for wall_column in column + 1..column + last {
+                self.clear((row + last, wall_column));
+                self.clear((row + last + 1, wall_column));
+            }
+        }
+    }
+}
+
+// a grid of about size by size tiles with a single closed loop of pipes through the start, and
+// junk pipes both inside and outside of it
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let block_size = rng.gen_range(3..=(size / 2).max(3));
+    generate_loop(rng, (size / block_size).max(1), block_size).0
+}
+
+// a size by size grid of blocks with block_size long sides, the loop goes around the outline of a
+// random tree of blocks. with n blocks in the tree and b = block_size - 2, the inside of each
+// block is b * b tiles and every join between two blocks opens up 2 * b more. also returns n
+fn generate_loop(rng: &mut impl Rng, size: usize, block_size: usize) -> (String, usize) {
+    let num_columns = size * block_size;
+    let mut pipes = Pipes {
+        links: vec![[false; 4]; num_columns * num_columns],
+        num_columns,
+        block_size,
+    };
+
+    // grow a random tree of blocks, covering between half and all of the grid
+    let target_blocks = rng.gen_range((size * size).div_ceil(2)..=size * size);
+    let mut in_tree = vec![false; size * size];
+    let first = (rng.gen_range(0..size), rng.gen_range(0..size));
+    in_tree[first.0 * size + first.1] = true;
+    pipes.add_ring(first.0, first.1);
+
+    let mut num_blocks = 1;
+    let mut frontier = vec![first];
+
+    while num_blocks < target_blocks {
+        let (row, column) = frontier[rng.gen_range(0..frontier.len())];
+
+        let neighbors = [
+            (row.checked_sub(1), Some(column)),
+            (Some(row + 1).filter(|&row| row < size), Some(column)),
+            (Some(row), column.checked_sub(1)),
+            (Some(row), Some(column + 1).filter(|&column| column < size)),
+        ]
+        .into_iter()
+        .filter_map(|(row, column)| Some((row?, column?)))
+        .filter(|&(row, column)| !in_tree[row * size + column])
+        .collect::<Vec<_>>();
+
+        let Some(&next) = neighbors.choose(rng) else {
+            frontier.retain(|&block| block != (row, column));
+            continue;
+        };
+
+        in_tree[next.0 * size + next.1] = true;
+        pipes.add_ring(next.0, next.1);
+        // joins always go from the block to the north or west
+        match (next.0 < row, next.0 > row, next.1 < column) {
+            (true, _, _) => pipes.join(next, SOUTH),
+            (_, true, _) => pipes.join((row, column), SOUTH),
+            (_, _, true) => pipes.join(next, EAST),
+            _ => pipes.join((row, column), EAST),
+        }
+
+        num_blocks += 1;
+        frontier.push(next);
+    }
+
+    let loop_cells = (0..pipes.links.len())
+        .filter(|&i| pipes.links[i].contains(&true))
+        .collect::<Vec<_>>();
+    let start = *loop_cells.choose(rng).unwrap();
+    let (start_row, start_column) = (start / num_columns, start % num_columns);
+
+    let mut input = String::with_capacity(num_columns * (num_columns + 1));
+
+    for row in 0..num_columns {
+        for column in 0..num_columns {
+            let links = pipes.links[row * num_columns + column];
+
+            let tile = if (row, column) == (start_row, start_column) {
+                b'S'
+            } else if links.contains(&true) {
+                PIPES
+                    .iter()
+                    .find(|(_, directions)| directions.iter().all(|&d| links[d]))
+                    .unwrap()
+                    .0
+            } else if rng.gen_bool(0.5) {
+                b'.'
+            } else {
+                let (pipe, directions) = *PIPES.choose(rng).unwrap();
+
+                // junk must not look like it connects to the start, or the loop would be ambiguous
+                let points_at_start = directions.iter().any(|&direction| {
+                    let (row, column) = (row as isize, column as isize);
+                    let (to_row, to_column) = match direction {
+                        NORTH => (row - 1, column),
+                        EAST => (row, column + 1),
+                        SOUTH => (row + 1, column),
+                        _ => (row, column - 1),
+                    };
+                    (to_row, to_column) == (start_row as isize, start_column as isize)
+                });
+
+                if points_at_start {
+                    b'.'
+                } else {
+                    pipe
+                }
+            };
+
+            input.push(tile as char);
+        }
+        input.push('\n');
+    }
+
+    (input, num_blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day10::Day10;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn check_answers(size: usize, block_size: usize, seed: u64) -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let (input, n) = generate_loop(&mut rng, size, block_size);
+        let grid = Day10::parse(&input)?;
+        let b = block_size - 2;
+
+        // the ring around each block is 4 * (b + 1) long, and each join removes 2 * b of it
+        assert_eq!(
+            Day10::part1(&grid)?,
+            (4 * (b + 1) * n - 2 * b * (n - 1)) / 2
+        );
+        assert_eq!(Day10::part2(&grid)?, b * b * n + 2 * b * (n - 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_answers() -> anyhow::Result<()> {
+        for (size, block_size) in [(1, 3), (2, 3), (5, 4), (20, 3), (7, 10)] {
+            check_answers(size, block_size, 10)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_loop() -> anyhow::Result<()> {
+        // big enough to overflow the stack when flood filling recursively
+        check_answers(2, 500, 1)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::random_grid;
+use rand::Rng;
+
+// a size by size image where about 1 in 20 cells is a galaxy
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    random_grid(
+        rng,
+        size,
+        |rng| if rng.gen_ratio(1, 20) { b'#' } else { b'.' },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day11::Day11;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(11);
+
+        for size in [1, 10, 200] {
+            let input = generate(&mut rng, size);
+            let image = Day11::parse(&input)?;
+
+            assert_eq!(image.len(), size);
+            // expanding the empty space further only moves the galaxies further apart
+            assert!(Day11::part1(&image)? <= Day11::part2(&image)?);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use rand::{seq::index, Rng};
+
+// the longest rows and the most unknown springs in a row, like the real inputs. part 2 repeats
+// each row 5 times, and every '?' more can double the number of arrangements
+const MAX_LEN: usize = 20;
+const MAX_UNKNOWN: usize = 10;
+
+// size rows of springs with the sizes of their groups of damaged springs. each row is a random
+// arrangement that fits its groups, with some springs then replaced by '?'
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let mut springs = Vec::with_capacity(MAX_LEN);
+        let mut groups = Vec::new();
+
+        // each group has at least one operational spring before it, except at the very start
+        loop {
+            let gap = rng.gen_range(usize::from(!groups.is_empty())..=3);
+            let group = rng.gen_range(1..=5);
+            if springs.len() + gap + group > MAX_LEN {
+                break;
+            }
+
+            springs.resize(springs.len() + gap, b'.');
+            springs.resize(springs.len() + group, b'#');
+            groups.push(group.to_string());
+        }
+        if groups.is_empty() {
+            springs.push(b'#');
+            groups.push("1".to_string());
+        }
+        let len = rng.gen_range(springs.len()..=MAX_LEN);
+        springs.resize(len, b'.');
+
+        let num_unknown = rng.gen_range(0..=MAX_UNKNOWN.min(springs.len()));
+        for i in index::sample(rng, springs.len(), num_unknown) {
+            springs[i] = b'?';
+        }
+
+        input.push_str(std::str::from_utf8(&springs).unwrap());
+        input.push_str(&format!(" {}\n", groups.join(",")));
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day12::Day12;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(12);
+
+        for size in [1, 10, 1000] {
+            let input = generate(&mut rng, size);
+            let rows = Day12::parse(&input)?;
+
+            // the arrangement each row was made from is always one of its arrangements
+            assert!(Day12::part1(&rows)? >= size);
+            assert!(Day12::part2(&rows)? >= Day12::part1(&rows)?);
+        }
+
+        Ok(())
+    }
+}
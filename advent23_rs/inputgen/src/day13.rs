@@ -0,0 +1,101 @@
+use rand::Rng;
+
+// size patterns of 5 to 17 by 5 to 17, separated by blank lines
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| pattern(rng))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// a pattern mirrored about a line between two rows, and about a line between two columns but for
+// one smudged cell, turned on its side half the time. part 1 only has one answer if nothing else
+// mirrors the pattern too, so the few that do are thrown away
+fn pattern(rng: &mut impl Rng) -> String {
+    loop {
+        let num_rows = rng.gen_range(5..=17);
+        let num_columns = rng.gen_range(5..=17);
+        let mut rows = (0..num_rows)
+            .map(|_| (0..num_columns).map(|_| rng.gen()).collect())
+            .collect::<Vec<Vec<bool>>>();
+
+        // at least one row is left below the reflection, for the smudge
+        let above = rng.gen_range(1..=(num_rows - 1) / 2);
+        for i in 0..above {
+            rows[above + i] = rows[above - 1 - i].clone();
+        }
+
+        // every row is mirrored the same way, so the rows above still match the ones below
+        let left = rng.gen_range(1..num_columns);
+        let width = left.min(num_columns - left);
+        for row in &mut rows {
+            for j in 0..width {
+                row[left + j] = row[left - 1 - j];
+            }
+        }
+
+        let row = rng.gen_range(2 * above..num_rows);
+        let column = rng.gen_range(left - width..left + width);
+        rows[row][column] = !rows[row][column];
+
+        if mirror_lines(&rows) != [above] || !mirror_lines(&transpose(&rows)).is_empty() {
+            continue;
+        }
+
+        if rng.gen() {
+            rows = transpose(&rows);
+        }
+
+        return rows
+            .iter()
+            .map(|row| {
+                let mut line = row
+                    .iter()
+                    .map(|&rock| if rock { '#' } else { '.' })
+                    .collect::<String>();
+                line.push('\n');
+                line
+            })
+            .collect();
+    }
+}
+
+// the lines between rows the pattern is mirrored about, as the number of rows above them
+fn mirror_lines(rows: &[Vec<bool>]) -> Vec<usize> {
+    (1..rows.len())
+        .filter(|&above| {
+            (0..above.min(rows.len() - above)).all(|i| rows[above - 1 - i] == rows[above + i])
+        })
+        .collect()
+}
+
+fn transpose(rows: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..rows[0].len())
+        .map(|column| rows.iter().map(|row| row[column]).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day13::Day13;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(13);
+
+        for size in [1, 10, 100] {
+            let input = generate(&mut rng, size);
+            let patterns = Day13::parse(&input)?;
+
+            assert_eq!(patterns.len(), size);
+            Day13::part1(&patterns)?;
+            Day13::part2(&patterns)?;
+        }
+
+        Ok(())
+    }
+}
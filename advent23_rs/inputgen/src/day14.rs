@@ -0,0 +1,37 @@
+use crate::random_grid;
+use rand::Rng;
+
+// a size by size platform with rounded rocks on about 1 in 5 cells and cube rocks on 1 in 8
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    random_grid(rng, size, |rng| match rng.gen_range(0..40) {
+        0..=7 => b'O',
+        8..=12 => b'#',
+        _ => b'.',
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day14::Day14;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(14);
+
+        for size in [1, 10, 60] {
+            let input = generate(&mut rng, size);
+            let platform = Day14::parse(&input)?;
+
+            // no rounded rock is further from the south edge than the north edge is
+            let num_rounded = input.bytes().filter(|&c| c == b'O').count();
+            assert!(Day14::part1(&platform)? <= num_rounded * size);
+            assert!(Day14::part2(&platform)? <= num_rounded * size);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::random_names;
+use rand::{seq::SliceRandom, Rng};
+
+// size steps on one line, putting in or taking out lenses with labels from a pool of about
+// size / 4 of them, so lenses are often replaced or taken out again
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let labels = random_names(rng, size / 4 + 1, &[]);
+
+    let steps = (0..size.max(1))
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_ratio(1, 3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    steps.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day15::Day15;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(15);
+
+        for size in [1, 10, 5000] {
+            let input = generate(&mut rng, size);
+            let parsed = Day15::parse(&input)?;
+
+            assert_eq!(parsed.1.len(), size);
+            assert!(Day15::part1(&parsed)? <= 255 * size as u64);
+            Day15::part2(&parsed)?;
+        }
+
+        Ok(())
+    }
+}
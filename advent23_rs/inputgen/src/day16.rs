@@ -0,0 +1,40 @@
+use crate::random_grid;
+use rand::{seq::SliceRandom, Rng};
+
+const MIRRORS: &[u8] = b"|-/\\";
+
+// a size by size contraption of empty space with a mirror or splitter on about 1 in 10 tiles
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    random_grid(rng, size, |rng| {
+        if rng.gen_ratio(1, 10) {
+            *MIRRORS.choose(rng).unwrap()
+        } else {
+            b'.'
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day16::Day16;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(16);
+
+        for size in [1, 10, 60] {
+            let input = generate(&mut rng, size);
+            let contraption = Day16::parse(&input)?;
+
+            let energized = Day16::part1(&contraption)?;
+            assert!((1..=size * size).contains(&energized));
+            assert!((energized..=size * size).contains(&Day16::part2(&contraption)?));
+        }
+
+        Ok(())
+    }
+}
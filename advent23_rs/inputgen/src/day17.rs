@@ -0,0 +1,36 @@
+use crate::random_grid;
+use rand::Rng;
+
+// an ultra crucible has to go 4 blocks before it can turn or stop, so it needs room for that
+const MIN_SIZE: usize = 5;
+
+// a size by size map of heat losses from 1 to 9, at least 5 by 5
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    random_grid(rng, size.max(MIN_SIZE), |rng| rng.gen_range(b'1'..=b'9'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day17::Day17;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(17);
+
+        for size in [1, 10, 60] {
+            let input = generate(&mut rng, size);
+            let map = Day17::parse(&input)?;
+
+            // every path enters at least size * 2 - 2 blocks, losing 1 to 9 heat in each
+            let size = size.max(MIN_SIZE);
+            assert!(Day17::part1(&map)? >= 2 * size - 2);
+            assert!(Day17::part2(&map)? >= Day17::part1(&map)?);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use rand::Rng;
+
+// the directions in the order part 2's digits give them
+const DIRECTIONS: [char; 4] = ['R', 'D', 'L', 'U'];
+
+// how far apart the corners of each plan can be. part 2's lengths are 5 hex digits, and no move
+// in a loop is more than 3 times this
+const PART1_SCALE: i64 = 10;
+const PART2_SCALE: i64 = 100_000;
+
+// a dig plan of 4 * size moves, with another plan of as many moves hidden in the colors
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let num_columns = size.max(1);
+    let part1 = random_loop(rng, num_columns, PART1_SCALE);
+    let part2 = random_loop(rng, num_columns, PART2_SCALE);
+
+    let mut input = String::new();
+    for ((direction, length), (hidden_direction, hidden_length)) in part1.into_iter().zip(part2) {
+        input.push_str(&format!(
+            "{} {length} (#{hidden_length:05x}{hidden_direction})\n",
+            DIRECTIONS[direction]
+        ));
+    }
+
+    input
+}
+
+// the moves, as an index into DIRECTIONS and a length, around num_columns columns side by side.
+// each column's top and bottom are different from its neighbours' and the columns overlap, so
+// there are exactly 4 moves per column and the loop never touches itself
+fn random_loop(rng: &mut impl Rng, num_columns: usize, scale: i64) -> Vec<(usize, i64)> {
+    // the x of each column's left side, then the right side of the last one
+    let mut xs = vec![0];
+    // the (bottom, top) of each column, with y going up
+    let mut columns = vec![(0, rng.gen_range(1..=scale))];
+
+    for _ in 1..num_columns {
+        xs.push(xs.last().unwrap() + rng.gen_range(1..=scale));
+
+        let &(bottom, top) = columns.last().unwrap();
+        let next_bottom = loop {
+            let next_bottom = rng.gen_range(bottom - scale..top);
+            if next_bottom != bottom {
+                break next_bottom;
+            }
+        };
+        let lowest_top = bottom.max(next_bottom) + 1;
+        let next_top = loop {
+            let next_top = rng.gen_range(lowest_top..=lowest_top + scale);
+            if next_top != top {
+                break next_top;
+            }
+        };
+        columns.push((next_bottom, next_top));
+    }
+    xs.push(xs.last().unwrap() + rng.gen_range(1..=scale));
+
+    // clockwise from the top left corner, along the tops, down the right side, back along the
+    // bottoms and up the left side
+    let mut corners = Vec::with_capacity(4 * num_columns);
+    for (i, &(_, top)) in columns.iter().enumerate() {
+        corners.push((xs[i], top));
+        corners.push((xs[i + 1], top));
+    }
+    for (i, &(bottom, _)) in columns.iter().enumerate().rev() {
+        corners.push((xs[i + 1], bottom));
+        corners.push((xs[i], bottom));
+    }
+
+    // no two corners in a row are the same, so each pair is one move
+    (0..corners.len())
+        .map(|i| {
+            let (x, y) = corners[i];
+            let (next_x, next_y) = corners[(i + 1) % corners.len()];
+            match (next_x - x, next_y - y) {
+                (dx, 0) if dx > 0 => (0, dx),
+                (0, dy) if dy < 0 => (1, -dy),
+                (dx, 0) => (2, -dx),
+                (0, dy) => (3, dy),
+                _ => unreachable!(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day18::Day18;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::HashSet;
+
+    // the cubic meters the plan digs out, by walking the trench and flood filling around it
+    fn dug_out(moves: &[(usize, i64)]) -> usize {
+        let mut trench = HashSet::from([(0, 0)]);
+        let mut position = (0i64, 0i64);
+        for &(direction, length) in moves {
+            let (dx, dy) = [(1, 0), (0, -1), (-1, 0), (0, 1)][direction];
+            for _ in 0..length {
+                position = (position.0 + dx, position.1 + dy);
+                assert!(trench.insert(position) || position == (0, 0));
+            }
+        }
+
+        let min_x = trench.iter().map(|p| p.0).min().unwrap() - 1;
+        let max_x = trench.iter().map(|p| p.0).max().unwrap() + 1;
+        let min_y = trench.iter().map(|p| p.1).min().unwrap() - 1;
+        let max_y = trench.iter().map(|p| p.1).max().unwrap() + 1;
+
+        let mut outside = HashSet::from([(min_x, min_y)]);
+        let mut stack = vec![(min_x, min_y)];
+        while let Some((x, y)) = stack.pop() {
+            for next in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                let inside_box =
+                    (min_x..=max_x).contains(&next.0) && (min_y..=max_y).contains(&next.1);
+                if inside_box && !trench.contains(&next) && outside.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        ((max_x - min_x + 1) * (max_y - min_y + 1)) as usize - outside.len()
+    }
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(18);
+
+        for size in [1, 10, 100] {
+            let input = generate(&mut rng, size);
+            let plans = Day18::parse(&input)?;
+
+            assert_eq!(input.lines().count(), 4 * size);
+            Day18::part1(&plans)?;
+            Day18::part2(&plans)?;
+        }
+
+        // the small loops can be dug out by hand to check them
+        for num_columns in [1, 2, 5, 20] {
+            let moves = random_loop(&mut rng, num_columns, PART1_SCALE);
+            let input = moves
+                .iter()
+                .map(|&(direction, length)| {
+                    format!("{} {length} (#00000{direction})\n", DIRECTIONS[direction])
+                })
+                .collect::<String>();
+
+            assert_eq!(Day18::part1(&Day18::parse(&input)?)?, dug_out(&moves));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::random_names;
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+// size workflows followed by size parts. like the real inputs the workflows form a tree rooted at
+// "in": every workflow other than "in" is the target of exactly one rule, so there are no cycles
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let num_workflows = size.max(1);
+    let mut names = vec!["in".to_string()];
+    names.extend(random_names(rng, num_workflows - 1, &["in"]));
+
+    // 1 to 3 conditional rules and the fallback rule, each rule's target starts unassigned
+    let mut targets = (0..num_workflows)
+        .map(|_| vec![None; rng.gen_range(2..=4)])
+        .collect::<Vec<_>>();
+
+    // hang every workflow off a random free rule of an earlier one, each workflow has at least
+    // 2 rules and uses up 1, so there is always a free rule left
+    let mut free_rules = (0..targets[0].len())
+        .map(|rule| (0, rule))
+        .collect::<Vec<_>>();
+    for workflow in 1..num_workflows {
+        let (parent, rule) = free_rules.swap_remove(rng.gen_range(0..free_rules.len()));
+        targets[parent][rule] = Some(workflow);
+        free_rules.extend((0..targets[workflow].len()).map(|rule| (workflow, rule)));
+    }
+
+    let mut lines = targets
+        .iter()
+        .zip(&names)
+        .map(|(targets, name)| {
+            let rules = targets
+                .iter()
+                .enumerate()
+                .map(|(i, target)| {
+                    let target = match target {
+                        Some(workflow) => names[*workflow].as_str(),
+                        None if rng.gen_bool(0.5) => "A",
+                        None => "R",
+                    };
+
+                    if i == targets.len() - 1 {
+                        target.to_string()
+                    } else {
+                        let category = CATEGORIES.choose(rng).unwrap();
+                        let comparison = if rng.gen_bool(0.5) { '<' } else { '>' };
+                        let value = rng.gen_range(1..=4000);
+                        format!("{category}{comparison}{value}:{target}")
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            format!("{name}{{{}}}", rules.join(","))
+        })
+        .collect::<Vec<_>>();
+
+    lines.shuffle(rng);
+    lines.push(String::new());
+
+    for _ in 0..size.max(1) {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        lines.push(format!("{{x={x},m={m},a={a},s={s}}}"));
+    }
+
+    let mut input = lines.join("\n");
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day19::Day19;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(19);
+
+        for size in [1, 10, 2000] {
+            let input = generate(&mut rng, size);
+            let parsed = Day19::parse(&input)?;
+
+            assert_eq!(parsed.0.len(), size);
+            assert_eq!(parsed.1.len(), size);
+            assert!(Day19::part1(&parsed)? <= 4 * 4000 * size as u64);
+            assert!(Day19::part2(&parsed)? <= 4000u64.pow(4));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use rand::{seq::SliceRandom, Rng};
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+// size games numbered from 1, each with 1 to 6 reveals of 1 to 20 cubes of up to 3 colors
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for id in 1..=size.max(1) {
+        let reveals = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let num_colors = rng.gen_range(1..=COLORS.len());
+                COLORS
+                    .choose_multiple(rng, num_colors)
+                    .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+
+        input.push_str(&format!("Game {id}: {}\n", reveals.join("; ")));
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day2::Day2;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+
+        for size in [1, 10, 5000] {
+            let input = generate(&mut rng, size);
+            let games = Day2::parse(&input)?;
+
+            assert_eq!(games.len(), size);
+            assert!(Day2::part1(&games)? <= (size * (size + 1) / 2) as i64);
+            Day2::part2(&games)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::random_names;
+
+const NUM_BITS: usize = 12;
+
+// a network shaped like the real inputs, with size counters feeding the conjunction jz, which
+// sends to rx. each counter is a chain of flip-flops counting button presses in binary, and a hub
+// conjunction that fires and resets the counter once it reaches its period
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    generate_network(rng, size).0
+}
+
+// also returns the period of each counter, rx first gets a low pulse at the lcm of the periods
+fn generate_network(rng: &mut impl Rng, size: usize) -> (String, Vec<usize>) {
+    let num_counters = size.max(1);
+    // per counter: the flip-flops, the hub and an inverter between the hub and jz
+    let mut names = random_names(rng, num_counters * (NUM_BITS + 2), &["jz", "rx"]).into_iter();
+
+    let mut lines = vec!["&jz -> rx".to_string()];
+    let mut first_flip_flops = Vec::new();
+    let mut periods = Vec::new();
+
+    for _ in 0..num_counters {
+        // the highest and lowest bits have to be set for the counter to reset cleanly
+        let period = rng.gen_range(1 << (NUM_BITS - 1)..1 << NUM_BITS) | 1;
+        let flip_flops = names.by_ref().take(NUM_BITS).collect::<Vec<_>>();
+        let hub = names.next().unwrap();
+        let inverter = names.next().unwrap();
+
+        let mut hub_successors = vec![inverter.clone(), flip_flops[0].clone()];
+
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut successors = Vec::new();
+            if let Some(next) = flip_flops.get(bit + 1) {
+                successors.push(next.clone());
+            }
+
+            // set bits report to the hub, unset bits get set by the hub when it fires
+            if period & (1 << bit) != 0 {
+                successors.push(hub.clone());
+            } else {
+                hub_successors.push(flip_flop.clone());
+            }
+
+            successors.shuffle(rng);
+            lines.push(format!("%{flip_flop} -> {}", successors.join(", ")));
+        }
+
+        hub_successors.shuffle(rng);
+        lines.push(format!("&{hub} -> {}", hub_successors.join(", ")));
+        lines.push(format!("&{inverter} -> jz"));
+
+        first_flip_flops.push(flip_flops[0].clone());
+        periods.push(period);
+    }
+
+    lines.push(format!("broadcaster -> {}", first_flip_flops.join(", ")));
+    lines.shuffle(rng);
+
+    let mut input = lines.join("\n");
+    input.push('\n');
+    (input, periods)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day20::Day20;
+    use num::integer::lcm;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_part2() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(20);
+
+        for size in [1, 4, 5] {
+            let (input, periods) = generate_network(&mut rng, size);
+            let graph = Day20::parse(&input)?;
+
+            Day20::part1(&graph)?;
+            assert_eq!(
+                Day20::part2(&graph)?,
+                periods.into_iter().reduce(lcm).unwrap()
+            );
+        }
+
+        Ok(())
+    }
+}
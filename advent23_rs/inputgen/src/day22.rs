@@ -0,0 +1,77 @@
+use rand::Rng;
+use std::collections::HashSet;
+
+// the x and y coordinates of the bricks are below this, like in the real inputs
+const FOOTPRINT: u32 = 10;
+
+// size straight bricks of 1 to 4 cubes floating above the ground, no two of them share a cube
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let num_bricks = size.max(1);
+    // leave enough room that a free spot is quick to find
+    let max_z = (num_bricks as u32).max(10);
+    let mut occupied = HashSet::new();
+    let mut input = String::new();
+
+    for _ in 0..num_bricks {
+        loop {
+            let min = [
+                rng.gen_range(0..FOOTPRINT),
+                rng.gen_range(0..FOOTPRINT),
+                rng.gen_range(1..=max_z),
+            ];
+            let axis = rng.gen_range(0..3);
+            let mut max = min;
+            max[axis] += rng.gen_range(0..4);
+
+            if max[0] >= FOOTPRINT || max[1] >= FOOTPRINT {
+                continue;
+            }
+
+            let cubes = (0..=max[axis] - min[axis])
+                .map(|offset| {
+                    let mut cube = min;
+                    cube[axis] += offset;
+                    cube
+                })
+                .collect::<Vec<_>>();
+
+            if cubes.iter().any(|cube| occupied.contains(cube)) {
+                continue;
+            }
+
+            occupied.extend(cubes);
+            let [x1, y1, z1] = min;
+            let [x2, y2, z2] = max;
+            input.push_str(&format!("{x1},{y1},{z1}~{x2},{y2},{z2}\n"));
+            break;
+        }
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day22::Day22;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(22);
+
+        for size in [1, 10, 1500] {
+            let input = generate(&mut rng, size);
+            let bricks = Day22::parse(&input)?;
+
+            assert_eq!(bricks.len(), size);
+            // a single brick can always be removed
+            assert!(Day22::part1(&bricks)? >= 1);
+            Day22::part2(&bricks)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+use rand::{seq::SliceRandom, Rng};
+use std::collections::VecDeque;
+
+const OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const SLOPES: [u8; 4] = [b'^', b'>', b'v', b'<'];
+
+struct Maze {
+    tiles: Vec<u8>,
+    size: usize,
+}
+
+impl Maze {
+    fn is_open(&self, (row, column): (usize, usize)) -> bool {
+        self.tiles[row * self.size + column] != b'#'
+    }
+
+    fn set(&mut self, (row, column): (usize, usize), tile: u8) {
+        self.tiles[row * self.size + column] = tile;
+    }
+
+    // the open neighbors of a tile, with the index of the direction to get to them
+    fn open_neighbors(&self, (row, column): (usize, usize)) -> Vec<(usize, (usize, usize))> {
+        OFFSETS
+            .iter()
+            .enumerate()
+            .filter_map(|(direction, &(row_offset, column_offset))| {
+                let next = (
+                    row.checked_add_signed(row_offset)?,
+                    column.checked_add_signed(column_offset)?,
+                );
+                (next.0 < self.size && next.1 < self.size && self.is_open(next))
+                    .then_some((direction, next))
+            })
+            .collect()
+    }
+
+    fn is_junction(&self, position: (usize, usize)) -> bool {
+        self.open_neighbors(position).len() >= 3
+    }
+}
+
+// a 2 * size + 1 square maze of 1 wide paths from the top left to the bottom right, like the
+// real inputs. it starts as a random spanning tree of cells with size / 2 extra walls knocked
+// out to make loops, and the paths out of every junction get slopes that only let you walk away
+// from the start, so there are no cycles when the slopes are kept
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let cells = size.max(2);
+    let size = 2 * cells + 1;
+    let mut maze = Maze {
+        tiles: vec![b'#'; size * size],
+        size,
+    };
+    let cell_position = |(row, column): (usize, usize)| (2 * row + 1, 2 * column + 1);
+
+    // carve a spanning tree with a randomized depth first search
+    let mut visited = vec![false; cells * cells];
+    let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+    visited[0] = true;
+    maze.set(cell_position((0, 0)), b'.');
+
+    while let Some(&(row, column)) = stack.last() {
+        let unvisited = OFFSETS
+            .iter()
+            .filter_map(|&(row_offset, column_offset)| {
+                let next = (
+                    row.checked_add_signed(row_offset)?,
+                    column.checked_add_signed(column_offset)?,
+                );
+                (next.0 < cells && next.1 < cells && !visited[next.0 * cells + next.1])
+                    .then_some(next)
+            })
+            .collect::<Vec<_>>();
+
+        let Some(&next) = unvisited.choose(rng) else {
+            stack.pop();
+            continue;
+        };
+
+        visited[next.0 * cells + next.1] = true;
+        let (from, to) = (cell_position((row, column)), cell_position(next));
+        maze.set(((from.0 + to.0) / 2, (from.1 + to.1) / 2), b'.');
+        maze.set(to, b'.');
+        stack.push(next);
+    }
+
+    // knock out walls between cells, walls have an odd and an even coordinate
+    let mut num_loops = 0;
+    while num_loops < cells / 2 {
+        let wall = (rng.gen_range(1..size - 1), rng.gen_range(1..size - 1));
+        if (wall.0 + wall.1) % 2 == 1 && !maze.is_open(wall) {
+            maze.set(wall, b'.');
+            num_loops += 1;
+        }
+    }
+
+    let start = (0, 1);
+    let end = (size - 1, size - 2);
+    maze.set(start, b'.');
+    maze.set(end, b'.');
+
+    // order the junctions by their distance from the start, ties broken by position. the shortest
+    // path to the end only goes up in this order, so the end stays reachable with the slopes
+    let mut distances = vec![usize::MAX; size * size];
+    let mut queue = VecDeque::from([(start, 0)]);
+    distances[1] = 0;
+    while let Some((position, distance)) = queue.pop_front() {
+        for (_, next) in maze.open_neighbors(position) {
+            if distances[next.0 * size + next.1] == usize::MAX {
+                distances[next.0 * size + next.1] = distance + 1;
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    let order = |(row, column): (usize, usize)| (distances[row * size + column], row, column);
+
+    let junctions = (0..size * size)
+        .map(|i| (i / size, i % size))
+        .filter(|&position| maze.is_open(position) && maze.is_junction(position))
+        .collect::<Vec<_>>();
+
+    let mut slopes = Vec::new();
+    for &junction in &junctions {
+        for (direction, first) in maze.open_neighbors(junction) {
+            // follow the path to whatever is at its other end
+            let (mut previous, mut current) = (junction, first);
+            let other_end = loop {
+                if maze.is_junction(current) || current == start || current == end {
+                    break Some(current);
+                }
+
+                match maze
+                    .open_neighbors(current)
+                    .into_iter()
+                    .find(|&(_, next)| next != previous)
+                {
+                    Some((_, next)) => (previous, current) = (current, next),
+                    // a dead end
+                    None => break None,
+                }
+            };
+
+            let Some(other_end) = other_end else {
+                continue;
+            };
+            // the start and the end stay flat
+            if first == start || first == end {
+                continue;
+            }
+
+            let slope = if order(junction) < order(other_end) {
+                SLOPES[direction]
+            } else {
+                SLOPES[(direction + 2) % 4]
+            };
+            slopes.push((first, slope));
+        }
+    }
+
+    for (position, slope) in slopes {
+        maze.set(position, slope);
+    }
+
+    let mut input = String::with_capacity(size * (size + 1));
+    for row in maze.tiles.chunks(size) {
+        input.push_str(std::str::from_utf8(row).unwrap());
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day23::Day23;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    // try every path through the tiles, only feasible for small mazes
+    fn longest_path_len(tiles: &[&[u8]], slippery: bool) -> Option<usize> {
+        fn visit(
+            tiles: &[&[u8]],
+            slippery: bool,
+            (row, column): (usize, usize),
+            visited: &mut Vec<Vec<bool>>,
+        ) -> Option<usize> {
+            if row == tiles.len() - 1 {
+                return Some(0);
+            }
+
+            visited[row][column] = true;
+            let mut longest = None;
+
+            for (direction, &(row_offset, column_offset)) in OFFSETS.iter().enumerate() {
+                let tile = tiles[row][column];
+                if slippery && tile != b'.' && tile != SLOPES[direction] {
+                    continue;
+                }
+
+                let Some(next_row) = row.checked_add_signed(row_offset) else {
+                    continue;
+                };
+                let next_column = column.checked_add_signed(column_offset).unwrap();
+
+                if tiles[next_row][next_column] == b'#' || visited[next_row][next_column] {
+                    continue;
+                }
+
+                if let Some(len) = visit(tiles, slippery, (next_row, next_column), visited) {
+                    longest = longest.max(Some(len + 1));
+                }
+            }
+
+            visited[row][column] = false;
+            longest
+        }
+
+        let mut visited = vec![vec![false; tiles[0].len()]; tiles.len()];
+        visit(tiles, slippery, (0, 1), &mut visited)
+    }
+
+    #[test]
+    fn test_answers() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(23);
+
+        for size in [2, 4, 6] {
+            let input = generate(&mut rng, size);
+            let tiles = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+            let grid = Day23::parse(&input)?;
+
+            assert_eq!(Some(Day23::part1(&grid)?), longest_path_len(&tiles, true));
+            assert_eq!(Some(Day23::part2(&grid)?), longest_path_len(&tiles, false));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_maze() -> anyhow::Result<()> {
+        let input = generate(&mut ChaCha8Rng::seed_from_u64(1), 40);
+        let grid = Day23::parse(&input)?;
+
+        assert!(Day23::part1(&grid)? <= Day23::part2(&grid)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use rand::Rng;
+use std::{
+    collections::HashSet,
+    ops::{Range, RangeInclusive},
+};
+
+// a rock thrown from around here with about this speed hits every hailstone, like in the real
+// inputs where the positions are around 2 to 4 * 10^14 and the velocities in the hundreds
+const POSITIONS: Range<i64> = 200_000_000_000_000..400_000_000_000_000;
+const VELOCITIES: RangeInclusive<i64> = -300..=300;
+// the times the rock hits the hailstones
+const TIMES: Range<i64> = 1_000_000_000..100_000_000_000;
+
+fn random_velocity(rng: &mut impl Rng) -> [i64; 3] {
+    // part 1 divides by the velocities along x, so none of them are 0
+    [0; 3].map(|_| loop {
+        let velocity = rng.gen_range(VELOCITIES);
+        if velocity != 0 {
+            break velocity;
+        }
+    })
+}
+
+// size hailstones, at least 3 for part 2, that a rock thrown from a random position with a random
+// velocity hits at a different time each
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let rock_position = [0; 3].map(|_| rng.gen_range(POSITIONS));
+    let rock_velocity = random_velocity(rng);
+    let mut times = HashSet::new();
+    let mut input = String::new();
+
+    while times.len() < size.max(3) {
+        let time = rng.gen_range(TIMES);
+        if !times.insert(time) {
+            continue;
+        }
+
+        // where the hailstone started so it's where the rock is at time
+        let velocity = random_velocity(rng);
+        let position =
+            [0, 1, 2].map(|i| rock_position[i] + time * (rock_velocity[i] - velocity[i]));
+
+        let [px, py, pz] = position;
+        let [vx, vy, vz] = velocity;
+        input.push_str(&format!("{px}, {py}, {pz} @ {vx}, {vy}, {vz}\n"));
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day24::Day24;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(24);
+
+        for size in [1, 10, 300] {
+            let input = generate(&mut rng, size);
+            let hailstones = Day24::parse(&input)?;
+
+            let num_hailstones = size.max(3);
+            assert_eq!(hailstones.len(), num_hailstones);
+            assert!(Day24::part1(&hailstones)? <= num_hailstones * (num_hailstones - 1) / 2);
+            // part 2 writes 3 equations for each of 3 hailstones
+            assert_eq!(Day24::part2(&hailstones)?.matches(',').count(), 9);
+        }
+
+        Ok(())
+    }
+}
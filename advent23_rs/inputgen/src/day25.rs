@@ -0,0 +1,74 @@
+use crate::random_names;
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashMap;
+
+// each half is a ring where every component is wired to the next 2 along, so it takes at least 4
+// cut wires to split a half, and it needs 5 components for that
+const MIN_HALF: usize = 5;
+
+// about size components in two halves joined by 3 wires, like the real inputs. each wire is
+// listed once, on the line of either of its components
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let num_components = size.max(2 * MIN_HALF);
+    let names = random_names(rng, num_components, &[]);
+    let split = rng.gen_range(MIN_HALF..=num_components - MIN_HALF);
+    let (half_a, half_b) = names.split_at(split);
+
+    let mut wires = Vec::new();
+    for half in [half_a, half_b] {
+        for (i, component) in half.iter().enumerate() {
+            for step in [1, 2] {
+                wires.push((component, &half[(i + step) % half.len()]));
+            }
+        }
+    }
+    let ends_a = half_a.choose_multiple(rng, 3);
+    let ends_b = half_b.choose_multiple(rng, 3);
+    wires.extend(ends_a.zip(ends_b));
+
+    // group the wires by one of their components, picked at random
+    let mut lines = names
+        .iter()
+        .map(|name| (name, Vec::new()))
+        .collect::<HashMap<_, _>>();
+    for (a, b) in wires {
+        let (from, to) = if rng.gen() { (a, b) } else { (b, a) };
+        lines.get_mut(from).unwrap().push(to.as_str());
+    }
+
+    let mut lines = names
+        .iter()
+        .filter(|name| !lines[name].is_empty())
+        .map(|name| format!("{name}: {}\n", lines[name].join(" ")))
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day25::Day25;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(25);
+
+        for size in [1, 20, 200] {
+            let input = generate(&mut rng, size);
+            let graph = Day25::parse(&input)?;
+
+            let size = size.max(2 * MIN_HALF);
+            assert_eq!(graph.len(), size);
+            // the halves multiplied together, with at least MIN_HALF in each
+            let product = Day25::part1(&graph)?;
+            assert!(product >= MIN_HALF * (size - MIN_HALF));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use rand::{seq::SliceRandom, Rng};
+
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+// a size by size schematic that is mostly '.', with 1 to 3 digit numbers and symbols scattered
+// over it. the numbers on a row always have a '.' or a symbol between them
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut row = Vec::<u8>::with_capacity(size);
+
+        while row.len() < size {
+            match rng.gen_range(0..10) {
+                0 if row.last().is_none_or(|c| !c.is_ascii_digit()) => {
+                    let len = rng.gen_range(1..=3).min(size - row.len());
+                    let number =
+                        rng.gen_range(10usize.pow(len as u32 - 1)..10usize.pow(len as u32));
+                    row.extend(number.to_string().bytes());
+                }
+                1 => row.push(*SYMBOLS.choose(rng).unwrap()),
+                _ => row.push(b'.'),
+            }
+        }
+
+        input.push_str(std::str::from_utf8(&row).unwrap());
+        input.push('\n');
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day3::Day3;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+
+        for size in [1, 10, 300] {
+            let input = generate(&mut rng, size);
+            let parsed = Day3::parse(&input)?;
+
+            assert_eq!(parsed.0.len(), size);
+            assert!(parsed.0.iter().all(|row| row.len() == size));
+            Day3::part1(&parsed)?;
+            Day3::part2(&parsed)?;
+        }
+
+        Ok(())
+    }
+}
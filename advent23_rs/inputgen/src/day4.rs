@@ -0,0 +1,86 @@
+use rand::{
+    seq::{index, SliceRandom},
+    Rng,
+};
+
+const NUM_WINNING: usize = 10;
+const NUM_YOURS: usize = 25;
+
+// the most copies any card ends up with. a card with n matches adds its copies to the next n
+// cards, so with random matches the copies grow exponentially and part 2 overflows after a few
+// dozen cards. the real inputs keep them in the hundreds of thousands
+const MAX_COPIES: usize = 100_000;
+
+// right aligned to 2 digits like the real inputs
+fn join_numbers(numbers: &[usize]) -> String {
+    numbers
+        .iter()
+        .map(|number| format!("{number:>2}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// size cards of 10 winning numbers and 25 numbers you have, all below 100. like the puzzle says,
+// no card makes you win copies of cards past the end of the table
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut copies = vec![1; size];
+    let mut input = String::new();
+
+    for i in 0..size {
+        let mut num_matches = rng.gen_range(0..=NUM_WINNING).min(size - 1 - i);
+        while num_matches > 0
+            && copies[i + 1..=i + num_matches]
+                .iter()
+                .any(|&n| n + copies[i] > MAX_COPIES)
+        {
+            num_matches -= 1;
+        }
+        for j in i + 1..=i + num_matches {
+            copies[j] += copies[i];
+        }
+
+        // the first num_matches winning numbers are among yours too
+        let numbers = index::sample(rng, 99, NUM_WINNING + NUM_YOURS - num_matches)
+            .into_iter()
+            .map(|number| number + 1)
+            .collect::<Vec<_>>();
+        let winning = &numbers[..NUM_WINNING];
+        let mut yours = [&winning[..num_matches], &numbers[NUM_WINNING..]].concat();
+        yours.shuffle(rng);
+
+        input.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            i + 1,
+            join_numbers(winning),
+            join_numbers(&yours)
+        ));
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day4::Day4;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(4);
+
+        for size in [1, 10, 5000] {
+            let input = generate(&mut rng, size);
+            let cards = Day4::parse(&input)?;
+
+            assert_eq!(cards.len(), size);
+            assert!(Day4::part1(&cards)? <= (size << (NUM_WINNING - 1)) as i32);
+            assert!(Day4::part2(&cards)? <= (size * MAX_COPIES) as i32);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use rand::{seq::SliceRandom, Rng};
+
+// the values the maps cover, like the real inputs these are all the u32s
+const NUM_VALUES: usize = 1 << 32;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+// size seed ranges, and a map between each pair of categories made of size ranges. each map
+// splits the values into size blocks and moves them around, so no two ranges of a map overlap on
+// either side
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    let seeds = (0..size)
+        .map(|_| {
+            let start = rng.gen_range(0..NUM_VALUES - 1);
+            let len = rng.gen_range(1..=(NUM_VALUES - start).min(NUM_VALUES / size));
+            format!("{start} {len}")
+        })
+        .collect::<Vec<_>>();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for names in CATEGORIES.windows(2) {
+        let mut cuts = (0..size - 1)
+            .map(|_| rng.gen_range(1..NUM_VALUES))
+            .collect::<Vec<_>>();
+        cuts.extend([0, NUM_VALUES]);
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let mut blocks = cuts
+            .windows(2)
+            .map(|cut| cut[0]..cut[1])
+            .collect::<Vec<_>>();
+        blocks.shuffle(rng);
+
+        // the blocks are laid out again in the shuffled order to get where each one goes
+        let mut dest_start = 0;
+        let mut lines = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            lines.push(format!("{dest_start} {} {}\n", block.start, block.len()));
+            dest_start += block.len();
+        }
+        lines.shuffle(rng);
+
+        input.push_str(&format!("\n{}-to-{} map:\n", names[0], names[1]));
+        input.push_str(&lines.concat());
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day5::Day5;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(5);
+
+        for size in [1, 10, 500] {
+            let input = generate(&mut rng, size);
+            let parsed = Day5::parse(&input)?;
+
+            assert_eq!(parsed.0.len(), 2 * size);
+            assert_eq!(parsed.1.len(), CATEGORIES.len() - 1);
+            assert!(Day5::part1(&parsed)? < NUM_VALUES);
+            assert!(Day5::part2(&parsed)? <= Day5::part1(&parsed)?);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use rand::Rng;
+
+// part 2 reads the records of every race as one number, and 4 races of up to 4 digits each is as
+// many as fit in a usize
+const MAX_RACES: usize = 4;
+
+// size races, at most 4, each lasting 10 to 99 milliseconds with a record that can be beaten
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut times = Vec::new();
+    let mut records = Vec::new();
+
+    for _ in 0..size.clamp(1, MAX_RACES) {
+        let time = rng.gen_range(10..100usize);
+        // the furthest the boat can go is holding the button for half the race
+        let best = (time / 2) * (time - time / 2);
+        times.push(time);
+        records.push(rng.gen_range(0..best));
+    }
+
+    let column = |numbers: &[usize]| {
+        numbers
+            .iter()
+            .map(|number| format!("{number:>7}"))
+            .collect::<String>()
+    };
+
+    format!(
+        "Time:    {}\nDistance:{}\n",
+        column(&times),
+        column(&records)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day6::Day6;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(6);
+
+        for size in [1, 3, 10] {
+            let input = generate(&mut rng, size);
+            let parsed = Day6::parse(&input)?;
+
+            assert_eq!(parsed.0.len(), size.min(MAX_RACES));
+            // every record can be beaten, so there's at least one way to win each race
+            assert!(Day6::part1(&parsed)? >= 1);
+            Day6::part2(&parsed)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashSet;
+
+const CARDS: &[u8] = b"AKQJT98765432";
+
+// size different hands of 5 cards, each with a bid of 1 to 1000
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1).min(CARDS.len().pow(5));
+    let mut hands = HashSet::new();
+    let mut input = String::new();
+
+    while hands.len() < size {
+        let hand = (0..5)
+            .map(|_| *CARDS.choose(rng).unwrap() as char)
+            .collect::<String>();
+
+        if hands.insert(hand.clone()) {
+            input.push_str(&format!("{hand} {}\n", rng.gen_range(1..=1000)));
+        }
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day7::Day7;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+
+        for size in [1, 10, 1000] {
+            let input = generate(&mut rng, size);
+            let parsed = Day7::parse(&input)?;
+
+            assert_eq!(parsed.0.len(), size);
+            // every hand's rank times its bid, at most
+            let most = (size * (size + 1) / 2 * 1000) as i32;
+            assert!(Day7::part1(&parsed)? <= most);
+            assert!(Day7::part2(&parsed)? <= most);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use rand::Rng;
+
+const NUM_VALUES: usize = 21;
+
+// size histories of 21 values each. each history is a polynomial of degree 0 to 5, built up from
+// a random first value on each layer of differences
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size.max(1) {
+        let degree = rng.gen_range(0..=5);
+        // the first value of each layer, from the history itself down to the constant layer
+        let mut layers = (0..=degree)
+            .map(|_| rng.gen_range(-10i64..=10))
+            .collect::<Vec<_>>();
+
+        let mut values = Vec::with_capacity(NUM_VALUES);
+        for _ in 0..NUM_VALUES {
+            values.push(layers[0].to_string());
+            for layer in 0..degree {
+                layers[layer] += layers[layer + 1];
+            }
+        }
+
+        input.push_str(&values.join(" "));
+        input.push('\n');
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+    use day9::Day9;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_solves() -> anyhow::Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(9);
+
+        for size in [1, 10, 1000] {
+            let input = generate(&mut rng, size);
+            let histories = Day9::parse(&input)?;
+
+            assert_eq!(histories.len(), size);
+            Day9::part1(&histories)?;
+            Day9::part2(&histories)?;
+        }
+
+        Ok(())
+    }
+}
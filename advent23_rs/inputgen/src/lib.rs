@@ -0,0 +1,139 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day9;
+
+// the days that have a generator. days 8 and 21 are left out, their part 2 only solves inputs
+// built the way the real ones are, which a generator would have to copy rather than make up:
+// - day 8's ghosts each have to be on a Z exactly when their walk repeats, which a random network
+//   almost never does, the real ones are separate loops of a multiple of the instructions' length
+// - day 21's map has to be square with S in the middle of an empty row and column, and 26501365
+//   steps have to end on the edge of an even number of copies of it, which of the odd sizes only
+//   3 and 131 do
+pub const DAYS: [u8; 23] = [
+    1, 2, 3, 4, 5, 6, 7, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 22, 23, 24, 25,
+];
+
+// a random puzzle input for a day, the same day, size and seed always give the same input.
+// what size means depends on the day, see each day's generate
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    // chacha is used instead of StdRng since its output is stable across versions of rand
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let input = match day {
+        1 => day1::generate(&mut rng, size),
+        2 => day2::generate(&mut rng, size),
+        3 => day3::generate(&mut rng, size),
+        4 => day4::generate(&mut rng, size),
+        5 => day5::generate(&mut rng, size),
+        6 => day6::generate(&mut rng, size),
+        7 => day7::generate(&mut rng, size),
+        9 => day9::generate(&mut rng, size),
+        10 => day10::generate(&mut rng, size),
+        11 => day11::generate(&mut rng, size),
+        12 => day12::generate(&mut rng, size),
+        13 => day13::generate(&mut rng, size),
+        14 => day14::generate(&mut rng, size),
+        15 => day15::generate(&mut rng, size),
+        16 => day16::generate(&mut rng, size),
+        17 => day17::generate(&mut rng, size),
+        18 => day18::generate(&mut rng, size),
+        19 => day19::generate(&mut rng, size),
+        20 => day20::generate(&mut rng, size),
+        22 => day22::generate(&mut rng, size),
+        23 => day23::generate(&mut rng, size),
+        24 => day24::generate(&mut rng, size),
+        25 => day25::generate(&mut rng, size),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+// a size by size grid, at least 1 by 1, with one row per line and each cell picked by cell
+fn random_grid<R: Rng>(rng: &mut R, size: usize, mut cell: impl FnMut(&mut R) -> u8) -> String {
+    let size = size.max(1);
+    let mut input = String::with_capacity(size * (size + 1));
+
+    for _ in 0..size {
+        input.extend((0..size).map(|_| cell(rng) as char));
+        input.push('\n');
+    }
+
+    input
+}
+
+// count distinct lowercase names that are not in reserved, as short as possible but at least 2
+// letters long like the names in the real inputs
+fn random_names(rng: &mut impl Rng, count: usize, reserved: &[&str]) -> Vec<String> {
+    let mut len = 2;
+    // keep the name space at least twice as big as needed, so picking free names stays fast
+    while 26usize.pow(len) < 2 * (count + reserved.len()) {
+        len += 1;
+    }
+
+    let mut used = reserved
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<HashSet<_>>();
+    let mut names = Vec::with_capacity(count);
+
+    while names.len() < count {
+        let name = (0..len)
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        for day in DAYS {
+            assert_eq!(generate(day, 5, 42), generate(day, 5, 42));
+            assert_ne!(generate(day, 5, 42), generate(day, 5, 43));
+        }
+
+        assert_eq!(generate(8, 5, 42), None);
+    }
+
+    #[test]
+    fn test_random_names() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let names = random_names(&mut rng, 1000, &["in"]);
+
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), 1000);
+        assert!(names.iter().all(|name| name.len() == 3 && name != "in"));
+    }
+}
@@ -0,0 +1,310 @@
+use num_traits::Zero;
+use std::{
+    iter::{Product, Sum},
+    ops::{Range, Sub},
+};
+
+// a set of values made of half open ranges. the ranges are kept sorted, non-empty and apart from
+// each other, so equal sets always have the same ranges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // sorts the ranges and merges the ones that overlap or touch
+    fn normalize(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_unstable_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        IntervalSet { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= *value);
+        self.ranges
+            .get(i)
+            .is_some_and(|range| range.start <= *value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalize(ranges);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if overlap.start < overlap.end {
+                ranges.push(overlap);
+            }
+
+            // whichever ends first can't overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // pieces of ranges that are apart are also apart
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            while other.ranges.get(j).is_some_and(|cut| cut.end <= start) {
+                j += 1;
+            }
+
+            for cut in other.ranges[j..]
+                .iter()
+                .take_while(|cut| cut.start < range.end)
+            {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    // (the part inside other, the part outside other)
+    pub fn split(&self, other: &Self) -> (Self, Self) {
+        (self.intersection(other), self.difference(other))
+    }
+
+    // (the values below at, the values at or above at)
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+
+        for range in &self.ranges {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+
+        (IntervalSet { ranges: below }, IntervalSet { ranges: above })
+    }
+
+    // how many values are in the set
+    pub fn len(&self) -> T
+    where
+        T: Sub<Output = T> + Sum,
+    {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+}
+
+impl<T: Ord + Copy> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalize(vec![range])
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+// the values in a range in each of N dimensions, like x in 0..3 and y in 5..7
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperRect<T, const N: usize>(pub [Range<T>; N]);
+
+impl<T: Ord + Copy, const N: usize> HyperRect<T, N> {
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(|range| range.start >= range.end)
+    }
+
+    // how many points are inside, 0 if it's empty
+    pub fn volume(&self) -> T
+    where
+        T: Sub<Output = T> + Product + Zero,
+    {
+        if self.is_empty() {
+            return T::zero();
+        }
+
+        self.0.iter().map(|range| range.end - range.start).product()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = HyperRect(std::array::from_fn(|i| {
+            self.0[i].start.max(other.0[i].start)..self.0[i].end.min(other.0[i].end)
+        }));
+
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    // splits across a dimension into (the part below at, the part at or above at), leaving out
+    // either part if it's empty
+    pub fn split(self, dimension: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let range = &self.0[dimension];
+
+        if at <= range.start {
+            (None, Some(self))
+        } else if at >= range.end {
+            (Some(self), None)
+        } else {
+            let mut below = self.clone();
+            below.0[dimension].end = at;
+
+            let mut above = self;
+            above.0[dimension].start = at;
+
+            (Some(below), Some(above))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let set = IntervalSet::from_iter([8..10, 1..3, 5..5, 2..4, 4..6]);
+
+        assert_eq!(set.ranges(), [1..6, 8..10]);
+        assert_eq!(set.len(), 7);
+        assert_eq!(set.min(), Some(1));
+        assert!(set.contains(&5) && set.contains(&8));
+        assert!(!set.contains(&6) && !set.contains(&10) && !set.contains(&0));
+
+        let mut inserted = set.clone();
+        inserted.insert(6..8);
+        assert_eq!(inserted.ranges().len(), 1);
+        assert_eq!(inserted.ranges()[0], 1..10);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = IntervalSet::from_iter([0..5, 10..15, 20..25]);
+        let b = IntervalSet::from_iter([3..12, 14..21, 30..31]);
+
+        assert_eq!(a.union(&b).ranges(), [0..25, 30..31]);
+        assert_eq!(a.intersection(&b).ranges(), [3..5, 10..12, 14..15, 20..21]);
+        assert_eq!(a.difference(&b).ranges(), [0..3, 12..14, 21..25]);
+        assert_eq!(b.difference(&a).ranges(), [5..10, 15..20, 30..31]);
+
+        // every value is in exactly one part of the split
+        let (inside, outside) = a.split(&b);
+        assert_eq!(inside.union(&outside), a);
+        assert!(inside.intersection(&outside).is_empty());
+
+        let (below, above) = a.split_at(12);
+        assert_eq!(below.ranges(), [0..5, 10..12]);
+        assert_eq!(above.ranges(), [12..15, 20..25]);
+    }
+
+    #[test]
+    fn test_against_a_bitmap() {
+        // pseudo random sets of 0..64, checked against the same operations on bits
+        let mut seed = 7u64;
+        let mut random_set = || {
+            let mut bits = 0u64;
+            let mut set = IntervalSet::new();
+            for _ in 0..4 {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let (start, len) = ((seed >> 33) % 64, (seed >> 50) % 16);
+                let end = (start + len).min(64);
+                set.insert(start..end);
+                bits |= (start..end).fold(0, |bits, i| bits | 1 << i);
+            }
+            (set, bits)
+        };
+        let to_bits = |set: &IntervalSet<u64>| {
+            (0..64).fold(0, |bits, i| bits | (set.contains(&i) as u64) << i)
+        };
+
+        for _ in 0..100 {
+            let ((a, a_bits), (b, b_bits)) = (random_set(), random_set());
+
+            assert_eq!(to_bits(&a), a_bits);
+            assert_eq!(to_bits(&a.union(&b)), a_bits | b_bits);
+            assert_eq!(to_bits(&a.intersection(&b)), a_bits & b_bits);
+            assert_eq!(to_bits(&a.difference(&b)), a_bits & !b_bits);
+            assert_eq!(a.len(), a_bits.count_ones() as u64);
+        }
+    }
+
+    #[test]
+    fn test_hyper_rect() {
+        let rect = HyperRect([0..4, 10..20, 5..7]);
+        assert_eq!(rect.volume(), 80);
+        assert_eq!(HyperRect([0..4, 3..3]).volume(), 0);
+
+        let other = HyperRect([2..10, 0..15, 6..8]);
+        assert_eq!(
+            rect.intersection(&other),
+            Some(HyperRect([2..4, 10..15, 6..7]))
+        );
+        assert_eq!(rect.intersection(&HyperRect([4..5, 0..20, 0..10])), None);
+
+        let (below, above) = rect.clone().split(1, 12);
+        assert_eq!(below, Some(HyperRect([0..4, 10..12, 5..7])));
+        assert_eq!(above, Some(HyperRect([0..4, 12..20, 5..7])));
+        assert_eq!(rect.clone().split(0, 0), (None, Some(rect.clone())));
+        assert_eq!(rect.clone().split(2, 7), (Some(rect), None));
+    }
+}